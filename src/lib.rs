@@ -0,0 +1,1413 @@
+// Unreal Engine log tailing and parsing, used by the ue-tui binary and usable on its own:
+// LogTailer follows a file (or a command's output, or a recorded session) on a background
+// thread and yields parsed LogLines; the parse_* functions work on single lines.
+
+use std::{
+    fs::{self, File},
+    io::{BufRead, BufReader, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+/* ----------------------------- Log lines ----------------------------- */
+
+// Unreal verbosity, most severe first so `<=` means "at least as severe"
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Fatal,
+    Error,
+    Warning,
+    Display,
+    Log,
+    Verbose,
+}
+
+impl Verbosity {
+    pub const ALL: [Verbosity; 6] = [Verbosity::Fatal, Verbosity::Error, Verbosity::Warning, Verbosity::Display, Verbosity::Log, Verbosity::Verbose];
+
+    pub fn parse(token: &str) -> Option<Self> {
+        match token {
+            "Fatal" => Some(Verbosity::Fatal),
+            "Error" => Some(Verbosity::Error),
+            "Warning" => Some(Verbosity::Warning),
+            "Display" => Some(Verbosity::Display),
+            "Log" => Some(Verbosity::Log),
+            "Verbose" | "VeryVerbose" => Some(Verbosity::Verbose),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verbosity::Fatal => "Fatal",
+            Verbosity::Error => "Error",
+            Verbosity::Warning => "Warning",
+            Verbosity::Display => "Display",
+            Verbosity::Log => "Log",
+            Verbosity::Verbose => "Verbose",
+        }
+    }
+}
+
+// Color of a line or of an ANSI run: the 16 terminal colors, 256-color indexes and RGB. Renderers map
+// it to their own type (the binary to ratatui's)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LineColor {
+    Reset,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    DarkGray,
+    LightRed,
+    LightGreen,
+    LightYellow,
+    LightBlue,
+    LightMagenta,
+    LightCyan,
+    White,
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Clone)]
+pub struct LogLine {
+    // original full line as read
+    pub text: String,
+    pub color: LineColor,
+    // parsed pieces for richer rendering
+    pub ts: Option<String>,       // content of first [ ... ]
+    pub ts_time: Option<chrono::NaiveDateTime>, // `ts` parsed, when it is an Unreal timestamp
+    pub category: Option<String>, // e.g., LogRenderer
+    pub level: Option<Verbosity>, // explicit token after the category, e.g. "Warning:"
+    pub message: String,          // remainder after category, verbosity and colon
+    pub source: Option<String>,   // target key when several logs are merged
+    pub crash: bool,              // crash/assert marker line
+    pub ansi: Vec<(usize, LineColor)>, // foreground changes from stripped ANSI codes: (char offset in display_message, color)
+    pub continuation: bool,       // part of the previous line's message (stack trace, JSON blob); category, level and time are inherited
+}
+
+impl LogLine {
+    // Text rendered after the prefix columns: the parsed message, or the raw line if nothing was parsed
+    pub fn display_message(&self) -> &str {
+        if self.continuation { return self.text.as_str(); }
+        if self.category.is_some() || self.ts.is_some() { self.message.as_str() } else { self.text.as_str() }
+    }
+
+    // Lines without a timestamp, category or verbosity of their own; crash markers head their own group
+    pub fn is_continuation_candidate(&self) -> bool {
+        self.continuation || (self.ts.is_none() && self.category.is_none() && self.level.is_none() && !self.crash)
+    }
+
+    // Attach to `parent` (the previous line of the same source): take its category, verbosity and
+    // time so filters keep the group together; color rules still see the line's own text
+    pub fn continue_from(&mut self, parent: &LogLine, color_rules: &[ColorRule]) {
+        self.continuation = true;
+        self.category = parent.category.clone();
+        self.level = parent.level;
+        self.ts_time = parent.ts_time;
+        self.color = classify_line(&self.text, self.level, color_rules);
+    }
+
+    // Undo continue_from; the inherited fields were never parsed from this line
+    pub fn detach(&mut self, color_rules: &[ColorRule]) {
+        if !self.continuation { return; }
+        self.continuation = false;
+        self.category = None;
+        self.level = None;
+        self.ts_time = None;
+        self.color = classify_line(&self.text, None, color_rules);
+    }
+}
+
+// One line of a `--record` session file (JSON Lines); color is re-derived from the rules on replay
+#[derive(Serialize, Deserialize)]
+pub struct RecordedLine {
+    text: String,
+    ts: Option<String>,
+    category: Option<String>,
+    level: Option<String>,
+    message: String,
+    source: Option<String>,
+    crash: bool,
+}
+
+impl RecordedLine {
+    pub fn from_line(l: &LogLine) -> Self {
+        Self {
+            text: l.text.clone(),
+            ts: l.ts.clone(),
+            category: l.category.clone(),
+            level: l.level.map(|v| v.as_str().to_string()),
+            message: l.message.clone(),
+            source: l.source.clone(),
+            crash: l.crash,
+        }
+    }
+
+    pub fn into_line(self, color_rules: &[ColorRule]) -> LogLine {
+        let level = self.level.as_deref().and_then(Verbosity::parse);
+        LogLine {
+            color: classify_line(&self.text, level, color_rules),
+            ts_time: self.ts.as_deref().and_then(parse_ue_timestamp),
+            text: self.text,
+            ts: self.ts,
+            category: self.category,
+            level,
+            message: self.message,
+            source: self.source,
+            crash: self.crash,
+            ansi: Vec::new(),
+            continuation: false,
+        }
+    }
+}
+
+// A color rule (the binary compiles them from its config); evaluated in order, first match wins
+#[derive(Debug)]
+pub struct ColorRule {
+    pub matcher: Matcher,
+    pub color: LineColor,
+}
+#[derive(Debug)]
+pub enum Matcher {
+    Contains(String),          // stored lowercase
+    Regex(regex::Regex),
+}
+
+/* ------------------------------ Parsing ------------------------------ */
+
+// Splits a line into (timestamp, category, verbosity, message). Grammar:
+//   line     := [ "[" ts "]" [ "[" thread "]" ] ] [ category ":" ws ] [ verbosity ":" ws ] message
+//   category := letter { letter | digit | "_" }
+// The category colon must be followed by whitespace or end of line, so "C:\Path" and
+// "http://host" stay in the message. A verbosity word with no category ("Error: ...")
+// sets the level only. Anything that doesn't fit is returned whole as the message.
+pub fn parse_log_components(s: &str) -> (Option<String>, Option<String>, Option<Verbosity>, String) {
+    let bytes = s.as_bytes();
+    let mut ts: Option<String> = None;
+
+    // helper to skip spaces
+    let skip_spaces = |i: usize| -> usize {
+        let mut j = i;
+        while j < bytes.len() && bytes[j].is_ascii_whitespace() { j += 1; }
+        j
+    };
+
+    let mut pos = 0usize;
+    if bytes.first() == Some(&b'[') {
+        if let Some(end) = s.find(']') {
+            ts = Some(s[1..end].to_string());
+            pos = end + 1;
+            pos = skip_spaces(pos);
+            // optional second bracket [number]
+            if bytes.get(pos) == Some(&b'[') {
+                if let Some(end2rel) = s[pos..].find(']') {
+                    pos = pos + end2rel + 1;
+                    pos = skip_spaces(pos);
+                }
+            }
+        }
+    }
+
+    let mut rest = s[pos..].trim_start();
+    let mut category: Option<String> = None;
+    let mut level: Option<Verbosity> = None;
+    if let Some((word, tail)) = split_label(rest) {
+        match Verbosity::parse(word) {
+            Some(v) => level = Some(v),
+            None => category = Some(word.to_string()),
+        }
+        rest = tail;
+        // Optional verbosity token: "LogTemp: Warning: message"
+        if level.is_none() {
+            if let Some((v, tail)) = split_label(rest).and_then(|(w, t)| Some((Verbosity::parse(w)?, t))) {
+                level = Some(v);
+                rest = tail;
+            }
+        }
+    }
+    (ts, category, level, rest.to_string())
+}
+
+// "Word: tail" -> ("Word", "tail") when Word is an identifier and the colon ends a token
+fn split_label(s: &str) -> Option<(&str, &str)> {
+    let end = s.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(s.len());
+    let word = &s[..end];
+    if !word.starts_with(|c: char| c.is_ascii_alphabetic()) { return None; }
+    let tail = s[end..].strip_prefix(':')?;
+    if !(tail.is_empty() || tail.starts_with(char::is_whitespace)) { return None; }
+    Some((word, tail.trim_start()))
+}
+
+// Unreal timestamps look like "2024.01.31-12.30.45:123"
+pub fn parse_ue_timestamp(ts: &str) -> Option<chrono::NaiveDateTime> {
+    chrono::NaiveDateTime::parse_from_str(ts.trim(), "%Y.%m.%d-%H.%M.%S:%3f").ok()
+}
+
+pub fn classify_line(s: &str, level: Option<Verbosity>, rules: &[ColorRule]) -> LineColor {
+    let l = s.to_lowercase();
+    for rule in rules {
+        let hit = match &rule.matcher {
+            Matcher::Contains(sub) => l.contains(sub.as_str()),
+            Matcher::Regex(re) => re.is_match(s),
+        };
+        if hit { return rule.color; }
+    }
+    // parsed verbosity beats substring guessing
+    match level {
+        Some(Verbosity::Fatal) => return LineColor::LightRed,
+        Some(Verbosity::Error) => return LineColor::Red,
+        Some(Verbosity::Warning) => return LineColor::Yellow,
+        Some(Verbosity::Verbose) => return LineColor::Gray,
+        Some(_) => return LineColor::White,
+        None => {}
+    }
+    // built-in fallback
+    if l.contains("error") { LineColor::Red }
+    else if l.contains("warning") { LineColor::Yellow }
+    else { LineColor::White }
+}
+
+// Crash dumps and failed asserts; checked on the whole line so timestamp/thread prefixes don't matter
+pub fn is_crash_marker(s: &str) -> bool {
+    const MARKERS: [&str; 5] = ["=== critical error: ===", "assertion failed", "fatal error", "unhandled exception", "ensure condition failed"];
+    let l = s.to_ascii_lowercase();
+    MARKERS.iter().any(|m| l.contains(m))
+}
+
+// The '\r' of a "\r\n" ending goes; any other '\r' is a progress display redrawing itself in place, so
+// keep the last non-blank segment, which is what a terminal would have ended up showing
+fn last_overwrite(raw: &[u8]) -> &[u8] {
+    let raw = raw.strip_suffix(b"\r").unwrap_or(raw);
+    if !raw.contains(&b'\r') { return raw; }
+    raw.rsplit(|&b| b == b'\r')
+        .find(|seg| seg.iter().any(|b| !b.is_ascii_whitespace()))
+        .unwrap_or_default()
+}
+
+// Drop NULs and make other control characters (except tab) visible, so stray bytes can't
+// corrupt the terminal: C0 as caret notation (^[), DEL as ^?, C1 as <U+0085>
+pub fn sanitize_line(s: &str) -> String {
+    if !s.chars().any(|c| c.is_control() && c != '\t') {
+        return s.to_string();
+    }
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\t' => out.push(c),
+            '\0' => {}
+            '\u{1}'..='\u{1f}' => { out.push('^'); out.push((c as u8 + 64) as char); }
+            '\u{7f}' => out.push_str("^?"),
+            c if c.is_control() => out.push_str(&format!("<U+{:04X}>", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// Remove ANSI escape sequences, returning the plain text and the SGR foreground changes
+// as (byte offset in the plain text, color); LineColor::Reset marks a return to the default
+pub fn strip_ansi(s: &str) -> (String, Vec<(usize, LineColor)>) {
+    if !s.contains('\x1b') { return (s.to_string(), Vec::new()); }
+    let mut out = String::with_capacity(s.len());
+    let mut runs: Vec<(usize, LineColor)> = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' { out.push(c); continue; }
+        match chars.next() {
+            // CSI: parameters up to a final byte in @..~; only SGR ('m') carries colors
+            Some('[') => {
+                let mut params = String::new();
+                for p in chars.by_ref() {
+                    if ('@'..='~').contains(&p) {
+                        if p == 'm' {
+                            if let Some(color) = sgr_foreground(&params) { runs.push((out.len(), color)); }
+                        }
+                        break;
+                    }
+                    params.push(p);
+                }
+            }
+            // OSC (e.g. hyperlinks, titles): ends at BEL or ESC \
+            Some(']') => {
+                while let Some(p) = chars.next() {
+                    if p == '\x07' { break; }
+                    if p == '\x1b' { chars.next(); break; }
+                }
+            }
+            _ => {} // two-character escape
+        }
+    }
+    (out, runs)
+}
+
+// Last foreground change in an SGR parameter list ("1;31", "38;5;208", "0"), if any
+fn sgr_foreground(params: &str) -> Option<LineColor> {
+    const BASE: [LineColor; 8] = [LineColor::Black, LineColor::Red, LineColor::Green, LineColor::Yellow, LineColor::Blue, LineColor::Magenta, LineColor::Cyan, LineColor::Gray];
+    const BRIGHT: [LineColor; 8] = [LineColor::DarkGray, LineColor::LightRed, LineColor::LightGreen, LineColor::LightYellow, LineColor::LightBlue, LineColor::LightMagenta, LineColor::LightCyan, LineColor::White];
+    let codes: Vec<u16> = params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+    let mut color = None;
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 | 39 => color = Some(LineColor::Reset),
+            n @ 30..=37 => color = Some(BASE[(n - 30) as usize]),
+            n @ 90..=97 => color = Some(BRIGHT[(n - 90) as usize]),
+            // extended colors; background variants (48) are skipped the same way
+            n @ (38 | 48) => {
+                match codes.get(i + 1) {
+                    Some(5) => {
+                        if n == 38 { color = codes.get(i + 2).map(|&c| LineColor::Indexed(c.min(255) as u8)); }
+                        i += 2;
+                    }
+                    Some(2) => {
+                        if n == 38 {
+                            if let [r, g, b] = codes.get(i + 2..i + 5).unwrap_or(&[]) {
+                                color = Some(LineColor::Rgb(*r as u8, *g as u8, *b as u8));
+                            }
+                        }
+                        i += 4;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    color
+}
+
+// Decode, sanitize and classify one raw line; None for blank lines
+pub fn build_log_line(raw: &[u8], source: &Option<String>, color_rules: &[ColorRule]) -> Option<LogLine> {
+    let raw = last_overwrite(raw);
+    // ANSI codes go before sanitizing, which would otherwise show them as ^[[31m
+    let (clean, runs) = strip_ansi(&String::from_utf8_lossy(raw));
+    let line = sanitize_line(&clean);
+    if line.trim().is_empty() { return None; }
+    let (ts, category, level, message) = parse_log_components(&line);
+    let color = classify_line(&line, level, color_rules);
+    let mut l = LogLine { crash: is_crash_marker(&line), ts_time: ts.as_deref().and_then(parse_ue_timestamp), text: line, color, ts, category, level, message, source: source.clone(), ansi: Vec::new(), continuation: false };
+    // re-base the color runs onto the rendered message; sanitizing shifts offsets, so give up then
+    let msg_len = l.display_message().len();
+    if !runs.is_empty() && l.text == clean && l.text.ends_with(l.display_message()) {
+        let start = l.text.len() - msg_len;
+        for (at, c) in runs {
+            let offset = if at <= start { 0 } else { l.text[start..at].chars().count() };
+            if l.ansi.last().is_some_and(|&(prev, _)| prev == offset) { l.ansi.pop(); }
+            l.ansi.push((offset, c));
+        }
+    }
+    Some(l)
+}
+
+// Try to parse a COOK progress line like:
+// "LogCook: Display: Cooked packages 816 Packages Remain 4532 Total 5348"
+// Returns (cooked, remain, total). Total may be 0 if not present.
+pub fn parse_cook_progress_line(s: &str) -> Option<(u64, u64, u64)> {
+    let l = s.to_ascii_lowercase();
+    fn find_number_after(hay: &str, key: &str) -> Option<u64> {
+        if let Some(i) = hay.find(key) {
+            let mut j = i + key.len();
+            // skip spaces
+            while let Some(ch) = hay.chars().nth(j) { if ch.is_whitespace() { j += 1; } else { break; } }
+            // collect digits
+            let digits: String = hay[j..].chars().take_while(|c| c.is_ascii_digit()).collect();
+            if digits.is_empty() { None } else { digits.parse::<u64>().ok() }
+        } else { None }
+    }
+    let cooked = find_number_after(&l, "cooked packages ");
+    let remain = find_number_after(&l, "packages remain ");
+    let total = find_number_after(&l, "total ");
+    if cooked.is_some() || remain.is_some() {
+        Some((cooked.unwrap_or(0), remain.unwrap_or(0), total.unwrap_or(0)))
+    } else { None }
+}
+
+// Try to parse a shader compile progress line like:
+// "LogShaderCompilers: Display: Compiling shaders (123 outstanding)" or "... shaders 123 outstanding"
+// Returns the outstanding count.
+pub fn parse_shader_progress_line(s: &str) -> Option<u64> {
+    let l = s.to_ascii_lowercase();
+    let shaders = l.find("shader")?;
+    let out = l[shaders..].find("outstanding")? + shaders;
+    // last run of digits between "shader" and "outstanding"
+    let between = &l[shaders..out];
+    let end = between.rfind(|c: char| c.is_ascii_digit())? + 1;
+    let start = between[..end].rfind(|c: char| !c.is_ascii_digit()).map_or(0, |i| i + 1);
+    between[start..end].parse().ok()
+}
+
+// What the log was written by, from the LogInit lines near its top:
+//   LogInit: Engine Version: 5.3.2-29314046+++UE5+Release-5.3
+//   LogInit: Build Configuration: Development
+//   LogInit: Branch Name: ++UE5+Release-5.3
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BuildInfo {
+    pub engine_version: Option<String>, // "5.3.2", without changelist and branch
+    pub configuration: Option<String>,  // "Development", "Shipping", ...
+    pub branch: Option<String>,
+}
+
+impl BuildInfo {
+    // Startup lines are only read this far into a file
+    const HEAD_BYTES: u64 = 64 * 1024;
+
+    // Take what `line` reports; false when it is not one of the lines above
+    pub fn update(&mut self, line: &str) -> bool {
+        let Some(at) = line.find("LogInit: ") else { return false; };
+        let rest = &line[at + "LogInit: ".len()..];
+        let rest = rest.strip_prefix("Display: ").unwrap_or(rest);
+        let value = |v: &str| Some(v.trim().to_string()).filter(|v| !v.is_empty());
+        if let Some(v) = rest.strip_prefix("Engine Version: ") {
+            self.engine_version = value(v.split('-').next().unwrap_or(v));
+        } else if let Some(v) = rest.strip_prefix("Build Configuration: ") {
+            self.configuration = value(v);
+        } else if let Some(v) = rest.strip_prefix("Branch Name: ") {
+            self.branch = value(v);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    // "UE 5.3.2, Development, branch ++UE5+Release-5.3"; None until one of them was seen
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.engine_version.as_ref().map(|v| format!("UE {}", v)),
+            self.configuration.clone(),
+            self.branch.as_ref().map(|b| format!("branch {}", b)),
+        ].into_iter().flatten().collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    // A tail normally starts at the end of the file, past the startup lines, so read them from its head
+    pub fn from_file_head(path: &Path) -> BuildInfo {
+        let mut info = BuildInfo::default();
+        if let Ok(f) = File::open(path) {
+            let mut head = Vec::new();
+            let _ = f.take(Self::HEAD_BYTES).read_to_end(&mut head);
+            // UTF-16 logs start with a byte-order mark; anything else is read as UTF-8
+            let (text, _) = encoding_rs::Encoding::for_bom(&head).map_or(encoding_rs::UTF_8, |(enc, _)| enc).decode_with_bom_removal(&head);
+            for line in text.split('\n') {
+                info.update(line);
+            }
+        }
+        info
+    }
+}
+
+/* ---------------------------- Tail threads --------------------------- */
+
+// Delay between file checks in a tail thread
+#[derive(Clone, Copy)]
+pub enum PollInterval {
+    Fixed(Duration),
+    Adaptive,                            // 30ms right after data, doubling up to 300ms when idle
+}
+impl PollInterval {
+    pub const ADAPTIVE_MIN: Duration = Duration::from_millis(30);
+    pub const ADAPTIVE_MAX: Duration = Duration::from_millis(300);
+
+    pub fn label(self) -> String {
+        match self {
+            PollInterval::Fixed(d) => format!("poll {}ms", d.as_millis()),
+            PollInterval::Adaptive => format!("poll {}-{}ms", Self::ADAPTIVE_MIN.as_millis(), Self::ADAPTIVE_MAX.as_millis()),
+        }
+    }
+}
+
+// Per-thread settings for spawn_tail
+pub struct TailOptions {
+    pub source: Option<String>,      // tag for merged views
+    pub color_rules: Arc<Vec<ColorRule>>,
+    pub from_start: bool,            // stream the existing file content before tailing
+    pub poll: PollInterval,
+    pub robust: bool,                // poll by reading, for filesystems with stale metadata
+    pub queued: Option<Arc<AtomicUsize>>, // bumped for every Line sent; the receiver counts down what it has handled
+}
+impl Default for TailOptions {
+    fn default() -> Self {
+        Self { source: None, color_rules: Arc::default(), from_start: false, poll: PollInterval::Fixed(Duration::from_millis(150)), robust: false, queued: None }
+    }
+}
+
+// Where a tail thread reads its lines from
+#[derive(Clone)]
+pub enum LogSource {
+    File { path: PathBuf, robust: bool },
+    Command(String),           // stdout of a shell command; can't seek, so Clear only clears the display
+    Launch(PathBuf),           // start this program and stream its stdout/stderr, tagged as such
+    Replay { path: PathBuf, fast: bool }, // a `--record` file, at its original pace unless `fast`
+}
+impl std::fmt::Display for LogSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogSource::File { path, .. } => write!(f, "{}", path.display()),
+            LogSource::Command(c) => write!(f, "$ {}", c),
+            LogSource::Launch(exe) => write!(f, "output of {}", exe.display()),
+            LogSource::Replay { path, .. } => write!(f, "replay of {}", path.display()),
+        }
+    }
+}
+
+pub enum Cmd {
+    Clear,          // jump tail offset to EOF (ignored by command streams)
+    Stop,           // end the tail thread
+}
+
+pub enum TailEvent {
+    Line(LogLine),
+    Error(String),
+    Status(PathBuf, Option<u64>), // a tailed file's size, None while it doesn't exist; sent on change
+    BacklogDone,    // a tail started with from_start has caught up with EOF
+}
+
+// A tail thread with a channel of its own, for tools embedding the tailer without the TUI:
+//
+//     let tailer = LogTailer::new(LogSource::File { path, robust: false }, TailOptions::default());
+//     for line in tailer.lines() { println!("{:?} {}", line.level, line.message); }
+//
+// Dropping it stops the thread.
+pub struct LogTailer {
+    events: mpsc::Receiver<TailEvent>,
+    cmd: mpsc::Sender<Cmd>,
+}
+impl LogTailer {
+    pub fn new(log: LogSource, opts: TailOptions) -> Self {
+        let (tx, events) = mpsc::channel();
+        let (cmd, rx_cmd) = mpsc::channel();
+        spawn_source(log, opts, tx, rx_cmd);
+        Self { events, cmd }
+    }
+
+    // Every event: lines plus errors, file status and backlog completion
+    pub fn events(&self) -> &mpsc::Receiver<TailEvent> {
+        &self.events
+    }
+
+    // Parsed lines only, blocking until the next one; ends when the thread does
+    pub fn lines(&self) -> impl Iterator<Item = LogLine> + '_ {
+        self.events.iter().filter_map(|e| match e {
+            TailEvent::Line(l) => Some(l),
+            _ => None,
+        })
+    }
+
+    // Skip to the current end of the file (no-op for command streams and replays)
+    pub fn clear(&self) {
+        let _ = self.cmd.send(Cmd::Clear);
+    }
+}
+impl Drop for LogTailer {
+    fn drop(&mut self) {
+        let _ = self.cmd.send(Cmd::Stop);
+    }
+}
+
+// Start the thread for `log`, sending into `tx`; several sources may share one channel for a merged view.
+// For Command and Launch sources, joining the handle after Stop waits until the child is killed.
+pub fn spawn_source(log: LogSource, opts: TailOptions, tx: mpsc::Sender<TailEvent>, rx_cmd: mpsc::Receiver<Cmd>) -> thread::JoinHandle<()> {
+    match log {
+        LogSource::File { path, robust } => match compression(&path) {
+            Some(kind) => spawn_archive(path, kind, opts, tx, rx_cmd),
+            None => spawn_tail(path, TailOptions { robust, ..opts }, tx, rx_cmd),
+        },
+        LogSource::Command(command) => {
+            let mut shell = if cfg!(windows) {
+                let mut c = std::process::Command::new("cmd");
+                c.arg("/C");
+                c
+            } else {
+                let mut c = std::process::Command::new("sh");
+                c.arg("-c");
+                c
+            };
+            shell.arg(&command);
+            spawn_command_tail(shell, command, false, opts, tx, rx_cmd)
+        }
+        LogSource::Launch(exe) => {
+            let mut program = std::process::Command::new(&exe);
+            // packaged games expect to start in their own folder
+            if let Some(dir) = exe.parent().filter(|d| !d.as_os_str().is_empty()) {
+                program.current_dir(dir);
+            }
+            spawn_command_tail(program, exe.display().to_string(), true, opts, tx, rx_cmd)
+        }
+        LogSource::Replay { path, fast } => spawn_replay(path, fast, opts, tx, rx_cmd),
+    }
+}
+
+fn spawn_tail(path: PathBuf, opts: TailOptions, tx: mpsc::Sender<TailEvent>, rx_cmd: mpsc::Receiver<Cmd>) -> thread::JoinHandle<()> {
+    // Large backlogs are read in slices so memory stays bounded and lines start flowing at once
+    const READ_CHUNK: usize = 1 << 20;
+    let TailOptions { source, color_rules, from_start, poll, robust, queued } = opts;
+    thread::spawn(move || {
+        // Start from EOF unless asked for the backlog; we don't want to flood with old lines.
+        let mut offset: u64 = if from_start { 0 } else { match fs::metadata(&path) { Ok(m) => m.len(), Err(_) => 0 } };
+        let mut backlog_pending = from_start;
+        let mut delay = match poll { PollInterval::Fixed(d) => d, PollInterval::Adaptive => PollInterval::ADAPTIVE_MIN };
+        let mut pending = LineBuffer::counting(queued);
+        // Track file identity to handle recreation/rotation even when sizes match
+        let mut last_created: Option<std::time::SystemTime> = None;
+        let mut last_modified: Option<std::time::SystemTime> = None;
+        // Device + inode where available; survives a missing-file gap so a recreated log is read from its start
+        let mut last_id: Option<(u64, u64)> = None;
+        // Reported to the UI on change: file size (None while missing) and whether opening it fails
+        let mut last_status: Option<Option<u64>> = None;
+        let mut open_failed = false;
+        // Robust polling: hash of the bytes just before `offset`, to notice a rewrite that kept the size
+        let mut last_fingerprint: Option<u64> = None;
+        // The byte-order mark was checked; done again whenever reading restarts from the top
+        let mut sniffed = false;
+        // One read buffer for the life of the tail rather than one per poll
+        let mut buf = vec![0u8; READ_CHUNK];
+
+        loop {
+            // Commands (non-blocking); a dropped sender means nobody will read us any more
+            match rx_cmd.try_recv() {
+                Ok(Cmd::Clear) => {
+                    if let Ok(len) = fs::metadata(&path).map(|m| m.len()) {
+                        offset = len;
+                    }
+                    pending.clear();
+                    last_fingerprint = None; // the new offset has no fingerprint yet
+                }
+                Ok(Cmd::Stop) | Err(mpsc::TryRecvError::Disconnected) => return,
+                Err(mpsc::TryRecvError::Empty) => {}
+            }
+
+            // Try to read new data; a full chunk means there's likely more, so read again without waiting
+            let mut got_data = false;
+            let mut full_chunk = false;
+            let mut status: Option<u64> = None;
+            if robust {
+                // Network filesystems may serve cached sizes and mtimes: re-open and read from the handle instead
+                match File::open(&path) {
+                    Ok(mut f) => {
+                        open_failed = false;
+                        let fingerprint = tail_fingerprint(&mut f, offset);
+                        if last_fingerprint.is_some_and(|prev| prev != fingerprint) {
+                            offset = 0; // rewritten, truncated or replaced underneath us
+                            pending.clear();
+                        }
+                        if offset == 0 || !sniffed {
+                            sniffed = sniff_encoding(&mut f, &mut pending, &mut offset);
+                        }
+                        let n = f.seek(SeekFrom::Start(offset)).and_then(|_| f.read(&mut buf)).unwrap_or(0);
+                        if n > 0 {
+                            got_data = true;
+                            full_chunk = n == READ_CHUNK;
+                            offset += n as u64;
+                            if !pending.emit(&buf[..n], &source, &color_rules, &tx) {
+                                return; // receiver gone
+                            }
+                        }
+                        last_fingerprint = Some(tail_fingerprint(&mut f, offset));
+                        status = Some(offset);
+                        if full_chunk {
+                            continue; // more backlog to read; don't wait for the next poll
+                        }
+                    }
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound && !open_failed => {
+                        open_failed = true;
+                        let _ = tx.send(TailEvent::Error(format!("Cannot read {}: {}", path.display(), e)));
+                    }
+                    Err(_) => {}
+                }
+            } else {
+                match fs::metadata(&path) {
+                    Ok(meta) => {
+                        // Detect recreation/rotation: by file identity when the platform has one,
+                        // otherwise by timestamps (created() is missing on many Linux filesystems)
+                        let id = file_id(&meta);
+                        let created = meta.created().ok();
+                        let modified = meta.modified().ok();
+                        let replaced = match (last_id, id) {
+                            (Some(prev), Some(cur)) => cur != prev,
+                            _ => false,
+                        };
+
+                        // If creation time changed (or appears after being None), it's a new file
+                        let recreated = match (last_created, created) {
+                            (Some(prev), Some(cur)) => cur != prev,
+                            (None, Some(_)) => false, // first time we see it; don't jump to beginning unless len decreased
+                            _ => false,
+                        };
+                        // If modified time goes backwards (or drastically changes while len == offset), treat as rotation
+                        let mod_time_backwards = match (last_modified, modified) {
+                            (Some(prev), Some(cur)) => cur < prev,
+                            _ => false,
+                        };
+
+                        if replaced || (id.is_none() && (recreated || mod_time_backwards)) {
+                            offset = 0;
+                            pending.clear();
+                        }
+
+                        // Update identity trackers
+                        last_created = created.or(last_created);
+                        last_modified = modified.or(last_modified);
+                        last_id = id.or(last_id);
+
+                        let len = meta.len();
+                        status = Some(len);
+                        if offset > len { offset = 0; } // rotated or truncated
+
+                        if len > offset {
+                            let to_read = ((len - offset) as usize).min(READ_CHUNK);
+                            let opened = File::open(&path);
+                            match &opened {
+                                Err(e) if !open_failed => {
+                                    open_failed = true;
+                                    let _ = tx.send(TailEvent::Error(format!("Cannot read {}: {}", path.display(), e)));
+                                }
+                                Ok(_) => open_failed = false,
+                                Err(_) => {}
+                            }
+                            if let Ok(mut f) = opened {
+                                if offset == 0 || !sniffed {
+                                    sniffed = sniff_encoding(&mut f, &mut pending, &mut offset);
+                                }
+                                if f.seek(SeekFrom::Start(offset)).is_ok() {
+                                    match f.read(&mut buf[..to_read]) {
+                                        Ok(n) if n > 0 => {
+                                            got_data = true;
+                                            full_chunk = n == to_read;
+                                            offset += n as u64;
+                                            if !pending.emit(&buf[..n], &source, &color_rules, &tx) {
+                                                return; // receiver gone
+                                            }
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                            }
+                        }
+                        if full_chunk && offset < len {
+                            continue; // more backlog to read; don't wait for the next poll
+                        }
+                    }
+                    Err(_) => {
+                        // file not found yet – clear identity and wait
+                        last_created = None;
+                        last_modified = None;
+                    }
+                }
+            }
+            if last_status != Some(status) {
+                last_status = Some(status);
+                if tx.send(TailEvent::Status(path.clone(), status)).is_err() {
+                    return;
+                }
+            }
+            if backlog_pending {
+                backlog_pending = false;
+                let _ = tx.send(TailEvent::BacklogDone);
+            }
+
+            if let PollInterval::Adaptive = poll {
+                delay = if got_data { PollInterval::ADAPTIVE_MIN } else { (delay * 2).min(PollInterval::ADAPTIVE_MAX) };
+            }
+            thread::sleep(delay);
+        }
+    })
+}
+
+// Longest line kept, in bytes; a longer one is cut here and the rest of it skipped up to its newline,
+// so a runaway "line" can't eat memory or stall rendering
+pub const MAX_LINE_BYTES: usize = 64 * 1024;
+const TRUNCATED_MARKER: &str = " … (line truncated)";
+
+// Splits raw reads into lines. The trailing partial line stays undecoded so a UTF-8 sequence split
+// by a read survives
+#[derive(Default)]
+struct LineBuffer {
+    carry: Vec<u8>,
+    overlong: bool, // the current line already went out truncated; drop the rest up to its newline
+    decoder: Option<encoding_rs::Decoder>, // UTF-16 input is turned into UTF-8 first; it keeps a half code unit between reads
+    queued: Option<Arc<AtomicUsize>>,      // TailOptions::queued
+    sent: usize,                           // lines sent so far
+}
+
+impl LineBuffer {
+    fn counting(queued: Option<Arc<AtomicUsize>>) -> Self {
+        Self { queued, ..Self::default() }
+    }
+
+    fn clear(&mut self) {
+        self.carry.clear();
+        self.overlong = false;
+        if let Some(decoder) = &mut self.decoder {
+            *decoder = decoder.encoding().new_decoder_with_bom_removal();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.carry.is_empty()
+    }
+
+    // Decode what follows as UTF-16 when `head`, the first bytes of the log, starts with its byte-order mark
+    fn detect_encoding(&mut self, head: &[u8]) {
+        let utf16 = encoding_rs::Encoding::for_bom(head).map(|(enc, _)| enc).filter(|&enc| enc != encoding_rs::UTF_8);
+        if utf16 != self.decoder.as_ref().map(|d| d.encoding()) {
+            self.decoder = utf16.map(|enc| enc.new_decoder_with_bom_removal());
+        }
+    }
+
+    // Bytes per code unit of the input
+    fn unit(&self) -> u64 {
+        if self.decoder.is_some() { 2 } else { 1 }
+    }
+
+    // Send every line `data` completes; false once the receiver is gone
+    fn emit(&mut self, data: &[u8], source: &Option<String>, color_rules: &[ColorRule], tx: &mpsc::Sender<TailEvent>) -> bool {
+        let decoded;
+        let mut rest = match &mut self.decoder {
+            Some(decoder) => {
+                let mut out = String::with_capacity(decoder.max_utf8_buffer_length(data.len()).unwrap_or(data.len() * 3));
+                let _ = decoder.decode_to_string(data, &mut out, false);
+                decoded = out;
+                decoded.as_bytes()
+            }
+            None => data,
+        };
+        while let Some(nl) = rest.iter().position(|&b| b == b'\n') {
+            let line = &rest[..nl];
+            rest = &rest[nl + 1..];
+            if std::mem::take(&mut self.overlong) { continue; }
+            let sent = if self.carry.is_empty() {
+                self.send(line, source, color_rules, tx)
+            } else {
+                self.carry.extend_from_slice(line);
+                let line = std::mem::take(&mut self.carry);
+                self.send(&line, source, color_rules, tx)
+            };
+            if !sent { return false; }
+        }
+        if self.overlong { return true; }
+        self.carry.extend_from_slice(rest);
+        if self.carry.len() > MAX_LINE_BYTES {
+            // no newline in sight: show what we have rather than wait for it
+            self.overlong = true;
+            let line = std::mem::take(&mut self.carry);
+            return self.send(&line, source, color_rules, tx);
+        }
+        true
+    }
+
+    fn send(&mut self, line: &[u8], source: &Option<String>, color_rules: &[ColorRule], tx: &mpsc::Sender<TailEvent>) -> bool {
+        self.sent += 1;
+        send_line(line, source, color_rules, tx, self.queued.as_deref())
+    }
+
+    // End of input: the last line may lack its newline
+    fn finish(&mut self, source: &Option<String>, color_rules: &[ColorRule], tx: &mpsc::Sender<TailEvent>) -> bool {
+        self.decoder = None; // a dangling half code unit is dropped
+        self.emit(b"\n", source, color_rules, tx)
+    }
+}
+
+// Re-read the byte-order mark at the start of the file; false while there are not two bytes to look at.
+// A UTF-16 tail has to start on a code unit boundary, so `offset` is rounded down to one
+fn sniff_encoding(f: &mut File, pending: &mut LineBuffer, offset: &mut u64) -> bool {
+    let mut head = [0u8; 2];
+    if f.seek(SeekFrom::Start(0)).and_then(|_| f.read_exact(&mut head)).is_err() {
+        return false;
+    }
+    pending.detect_encoding(&head);
+    *offset -= *offset % pending.unit();
+    true
+}
+
+fn send_line(raw: &[u8], source: &Option<String>, color_rules: &[ColorRule], tx: &mpsc::Sender<TailEvent>, queued: Option<&AtomicUsize>) -> bool {
+    let line = if raw.len() > MAX_LINE_BYTES {
+        // back up to a character boundary
+        let mut cut = MAX_LINE_BYTES;
+        while cut > 0 && raw[cut] & 0xC0 == 0x80 { cut -= 1; }
+        let mut bytes = raw[..cut].to_vec();
+        bytes.extend_from_slice(TRUNCATED_MARKER.as_bytes());
+        build_log_line(&bytes, source, color_rules)
+    } else {
+        build_log_line(raw, source, color_rules)
+    };
+    match line {
+        Some(line) => {
+            if let Some(queued) = queued { queued.fetch_add(1, Ordering::Relaxed); }
+            tx.send(TailEvent::Line(line)).is_ok()
+        }
+        None => true,
+    }
+}
+
+// Hash of up to 4 KB before `offset`; differs from the last poll's when the file was rewritten
+fn tail_fingerprint(f: &mut File, offset: u64) -> u64 {
+    use std::hash::{Hash, Hasher};
+    const WINDOW: u64 = 4096;
+    let start = offset.saturating_sub(WINDOW);
+    let mut buf = Vec::with_capacity((offset - start) as usize);
+    if f.seek(SeekFrom::Start(start)).is_ok() {
+        let _ = Read::by_ref(f).take(offset - start).read_to_end(&mut buf);
+    }
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    buf.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Identity of the file behind a path, to notice it being renamed away and recreated
+#[cfg(unix)]
+fn file_id(meta: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((meta.dev(), meta.ino()))
+}
+
+// Stable std has no file index on Windows; NTFS creation times are reliable there instead
+#[cfg(not(unix))]
+fn file_id(_meta: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+// Stream a child process's output. With `tag_streams`, stderr is read too and every line is tagged
+// "stdout"/"stderr" (after the target key in merged views). The child is killed on Stop.
+fn spawn_command_tail(
+    mut process: std::process::Command,
+    command: String,
+    tag_streams: bool,
+    opts: TailOptions,
+    tx: mpsc::Sender<TailEvent>,
+    rx_cmd: mpsc::Receiver<Cmd>,
+) -> thread::JoinHandle<()> {
+    let TailOptions { source, color_rules, from_start, poll, queued, .. } = opts;
+    let wait = match poll { PollInterval::Fixed(d) => d, PollInterval::Adaptive => PollInterval::ADAPTIVE_MAX };
+    thread::spawn(move || {
+        // a stream has no backlog to load
+        if from_start {
+            let _ = tx.send(TailEvent::BacklogDone);
+        }
+        let stderr = if tag_streams { std::process::Stdio::piped() } else { std::process::Stdio::null() };
+        let spawned = process
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(stderr)
+            .spawn();
+        let mut child = match spawned {
+            Ok(c) => c,
+            Err(e) => {
+                let _ = tx.send(TailEvent::Error(format!("Cannot run {:?}: {}", command, e)));
+                return;
+            }
+        };
+        let tag = |stream: &str| -> Option<String> {
+            if !tag_streams { return source.clone(); }
+            Some(match &source {
+                Some(key) => format!("{}:{}", key, stream),
+                None => stream.to_string(),
+            })
+        };
+        if let Some(stdout) = child.stdout.take() {
+            spawn_stream_reader(stdout, tag("stdout"), color_rules.clone(), tx.clone(), queued.clone());
+        }
+        if let Some(stderr) = child.stderr.take() {
+            spawn_stream_reader(stderr, tag("stderr"), color_rules.clone(), tx.clone(), queued.clone());
+        }
+
+        loop {
+            match rx_cmd.recv_timeout(wait) {
+                Ok(Cmd::Clear) | Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Ok(Cmd::Stop) | Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return;
+                }
+            }
+            if let Ok(Some(status)) = child.try_wait() {
+                let _ = tx.send(TailEvent::Error(format!("Command exited ({}): {}", status, command)));
+                return;
+            }
+        }
+    })
+}
+
+fn spawn_stream_reader(
+    stream: impl Read + Send + 'static,
+    source: Option<String>,
+    color_rules: Arc<Vec<ColorRule>>,
+    tx: mpsc::Sender<TailEvent>,
+    queued: Option<Arc<AtomicUsize>>,
+) {
+    thread::spawn(move || {
+        let mut stream = stream;
+        let mut buf = vec![0u8; 64 * 1024];
+        let mut pending = LineBuffer::counting(queued);
+        loop {
+            match stream.read(&mut buf) {
+                Ok(0) => {
+                    pending.finish(&source, &color_rules, &tx);
+                    return;
+                }
+                Ok(n) => {
+                    if !pending.emit(&buf[..n], &source, &color_rules, &tx) { return; }
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(_) => return,
+            }
+        }
+    });
+}
+
+// Compressed logs are recognised by extension, whether or not this build can read them
+fn compression(path: &Path) -> Option<&'static str> {
+    const KINDS: [&str; 7] = ["gz", "zip", "bz2", "xz", "zst", "7z", "lz4"];
+    let ext = path.extension()?.to_string_lossy().to_ascii_lowercase();
+    KINDS.into_iter().find(|k| *k == ext)
+}
+
+fn open_archive(path: &Path, kind: &str) -> Result<Box<dyn Read>, String> {
+    match kind {
+        #[cfg(feature = "gzip")]
+        "gz" => {
+            let file = File::open(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+            // CI archives can be several gzip members back to back
+            Ok(Box::new(flate2::read::MultiGzDecoder::new(BufReader::new(file))))
+        }
+        #[cfg(not(feature = "gzip"))]
+        "gz" => Err(format!("No gzip support in this build (cargo build --features gzip) for {}", path.display())),
+        other => Err(format!(".{} compression is not supported, only .gz: {}", other, path.display())),
+    }
+}
+
+// A finished, compressed log: decompressed and streamed once from the start, never followed
+fn spawn_archive(path: PathBuf, kind: &'static str, opts: TailOptions, tx: mpsc::Sender<TailEvent>, rx_cmd: mpsc::Receiver<Cmd>) -> thread::JoinHandle<()> {
+    let TailOptions { source, color_rules, from_start, queued, .. } = opts;
+    thread::spawn(move || {
+        let size = fs::metadata(&path).ok().map(|m| m.len());
+        let _ = tx.send(TailEvent::Status(path.clone(), size));
+        match open_archive(&path, kind) {
+            Ok(mut reader) => {
+                let mut buf = vec![0u8; 64 * 1024];
+                let mut pending = LineBuffer::counting(queued);
+                let mut first = true;
+                let outcome = loop {
+                    // big archives take a while; quitting shouldn't wait for them
+                    if let Ok(Cmd::Stop) | Err(mpsc::TryRecvError::Disconnected) = rx_cmd.try_recv() { return; }
+                    match reader.read(&mut buf) {
+                        Ok(0) => break Ok(()),
+                        Ok(n) => {
+                            if std::mem::take(&mut first) { pending.detect_encoding(&buf[..n]); }
+                            if !pending.emit(&buf[..n], &source, &color_rules, &tx) { return; }
+                        }
+                        Err(e) => break Err(e),
+                    }
+                };
+                // the last line may lack its newline
+                if !pending.is_empty() {
+                    pending.finish(&source, &color_rules, &tx);
+                }
+                let lines = pending.sent;
+                let _ = tx.send(TailEvent::Error(match outcome {
+                    Ok(()) => format!("Archive read: {} lines, not followed", lines),
+                    Err(e) => format!("Archive damaged after line {}: {}", lines, e),
+                }));
+            }
+            Err(e) => { let _ = tx.send(TailEvent::Error(e)); }
+        }
+        if from_start {
+            let _ = tx.send(TailEvent::BacklogDone);
+        }
+        // stay alive like a tail would, until stopped
+        while let Ok(Cmd::Clear) = rx_cmd.recv() {}
+    })
+}
+
+// Feed a `--record` file back as if it were being tailed; gaps between parsed timestamps are
+// slept through unless `fast`
+fn spawn_replay(path: PathBuf, fast: bool, opts: TailOptions, tx: mpsc::Sender<TailEvent>, rx_cmd: mpsc::Receiver<Cmd>) -> thread::JoinHandle<()> {
+    let TailOptions { color_rules, from_start, queued, .. } = opts;
+    thread::spawn(move || {
+        if from_start {
+            let _ = tx.send(TailEvent::BacklogDone);
+        }
+        let file = match File::open(&path) {
+            Ok(f) => f,
+            Err(e) => {
+                let _ = tx.send(TailEvent::Error(format!("Cannot read {}: {}", path.display(), e)));
+                return;
+            }
+        };
+        let mut previous: Option<chrono::NaiveDateTime> = None;
+        let mut count = 0usize;
+        let mut bad = 0usize;
+        for text in BufReader::new(file).lines() {
+            let Ok(text) = text else { break; };
+            let Ok(recorded) = serde_json::from_str::<RecordedLine>(&text) else {
+                bad += 1;
+                continue;
+            };
+            let line = recorded.into_line(&color_rules);
+            let mut wait = Duration::ZERO;
+            if let (false, Some(prev), Some(cur)) = (fast, previous, line.ts_time) {
+                wait = (cur - prev).to_std().unwrap_or_default();
+            }
+            previous = line.ts_time.or(previous);
+            // sleep in a way that still notices Stop
+            match rx_cmd.recv_timeout(wait) {
+                Ok(Cmd::Stop) | Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                Ok(Cmd::Clear) | Err(mpsc::RecvTimeoutError::Timeout) => {}
+            }
+            if let Some(queued) = &queued { queued.fetch_add(1, Ordering::Relaxed); }
+            if tx.send(TailEvent::Line(line)).is_err() {
+                return;
+            }
+            count += 1;
+        }
+        let skipped = if bad > 0 { format!(" ({} unreadable skipped)", bad) } else { String::new() };
+        let _ = tx.send(TailEvent::Error(format!("Replay finished: {} lines{}", count, skipped)));
+        // stay alive like a tail would, until stopped
+        while let Ok(Cmd::Clear) = rx_cmd.recv() {}
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Text of every event queued so far, which must all be lines
+    fn lines(rx: &mpsc::Receiver<TailEvent>) -> Vec<String> {
+        rx.try_iter().map(|e| match e {
+            TailEvent::Line(l) => l.text,
+            _ => panic!("expected only lines"),
+        }).collect()
+    }
+
+    #[test]
+    fn parses_timestamp_thread_category_and_verbosity() {
+        let (ts, category, level, message) =
+            parse_log_components("[2024.01.31-12.30.45:123][  7]LogRenderer: Warning: Texture streaming pool over budget");
+        assert_eq!(ts.as_deref(), Some("2024.01.31-12.30.45:123"));
+        assert_eq!(category.as_deref(), Some("LogRenderer"));
+        assert_eq!(level, Some(Verbosity::Warning));
+        assert_eq!(message, "Texture streaming pool over budget");
+        assert!(parse_ue_timestamp(ts.as_deref().unwrap()).is_some());
+    }
+
+    #[test]
+    fn line_without_category_keeps_whole_text() {
+        let (ts, category, level, message) = parse_log_components("Running with 8 threads");
+        assert_eq!((ts, category, level), (None, None, None));
+        assert_eq!(message, "Running with 8 threads");
+    }
+
+    // (line, ts, category, verbosity, message)
+    type Fixture = (&'static str, Option<&'static str>, Option<&'static str>, Option<Verbosity>, &'static str);
+    const FIXTURES: &[Fixture] = &[
+        ("[2024.01.31-12.30.45:123][  0]LogInit: Display: Engine is initialized.", Some("2024.01.31-12.30.45:123"), Some("LogInit"), Some(Verbosity::Display), "Engine is initialized."),
+        ("[2024.01.31-12.30.45:123]LogInit: Build: ++UE5+Release-5.3", Some("2024.01.31-12.30.45:123"), Some("LogInit"), None, "Build: ++UE5+Release-5.3"),
+        ("LogD3D12RHI: Chosen D3D12 Adapter Id = 0", None, Some("LogD3D12RHI"), None, "Chosen D3D12 Adapter Id = 0"),
+        ("LogText: Foo: Bar", None, Some("LogText"), None, "Foo: Bar"),
+        ("LogTemp: Warning: ratio 3:2 clamped", None, Some("LogTemp"), Some(Verbosity::Warning), "ratio 3:2 clamped"),
+        ("LogBlueprintUserMessages: [BP_Player_C_0] Hello", None, Some("LogBlueprintUserMessages"), None, "[BP_Player_C_0] Hello"),
+        ("LogTemp:", None, Some("LogTemp"), None, ""),
+        ("Log file open, 01/31/24 12:30:45", None, None, None, "Log file open, 01/31/24 12:30:45"),
+        ("12:30:45 build started", None, None, None, "12:30:45 build started"),
+        ("C:\\Projects\\Game\\Source\\Foo.cpp(12): error C2065: undeclared", None, None, None, "C:\\Projects\\Game\\Source\\Foo.cpp(12): error C2065: undeclared"),
+        ("Fetching https://example.com/manifest", None, None, None, "Fetching https://example.com/manifest"),
+        ("https://example.com: unreachable", None, None, None, "https://example.com: unreachable"),
+        ("Error: Unable to find package", None, None, Some(Verbosity::Error), "Unable to find package"),
+        ("Assertion failed: IsValid(Obj) [File:Foo.cpp] [Line: 42]", None, None, None, "Assertion failed: IsValid(Obj) [File:Foo.cpp] [Line: 42]"),
+        ("[2024.01.31-12.30.45:123][  7]Unparsed text", Some("2024.01.31-12.30.45:123"), None, None, "Unparsed text"),
+        ("[unterminated LogTemp: x", None, None, None, "[unterminated LogTemp: x"),
+        ("", None, None, None, ""),
+    ];
+
+    #[test]
+    fn parses_fixture_lines() {
+        for &(line, ts, category, level, message) in FIXTURES {
+            let got = parse_log_components(line);
+            assert_eq!(
+                (got.0.as_deref(), got.1.as_deref(), got.2, got.3.as_str()),
+                (ts, category, level, message),
+                "{line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_cook_progress() {
+        let line = "LogCook: Display: Cooked packages 816 Packages Remain 4532 Total 5348";
+        assert_eq!(parse_cook_progress_line(line), Some((816, 4532, 5348)));
+        assert_eq!(parse_cook_progress_line("LogCook: Display: Done"), None);
+    }
+
+    #[test]
+    fn parses_shader_progress() {
+        assert_eq!(parse_shader_progress_line("LogShaderCompilers: Display: Compiling shaders (123 outstanding)"), Some(123));
+    }
+
+    #[test]
+    fn classifies_by_rules_then_verbosity() {
+        let rules = [ColorRule { matcher: Matcher::Contains("niagara".into()), color: LineColor::Blue }];
+        assert_eq!(classify_line("LogNiagara: Warning: x", Some(Verbosity::Warning), &rules), LineColor::Blue);
+        assert_eq!(classify_line("LogTemp: Error: x", Some(Verbosity::Error), &[]), LineColor::Red);
+        assert_eq!(classify_line("something went wrong: error 5", None, &[]), LineColor::Red);
+    }
+
+    #[test]
+    fn strips_ansi_and_keeps_colors() {
+        let (text, runs) = strip_ansi("a \x1b[31mred\x1b[0m b");
+        assert_eq!(text, "a red b");
+        assert_eq!(runs, vec![(2, LineColor::Red), (5, LineColor::Reset)]);
+        let line = build_log_line(b"LogTemp: \x1b[32mok\x1b[m\r", &None, &[]).unwrap();
+        assert_eq!(line.text, "LogTemp: ok");
+        assert_eq!(line.ansi, vec![(0, LineColor::Green), (2, LineColor::Reset)]);
+    }
+
+    #[test]
+    fn truncates_overlong_lines_and_skips_their_rest() {
+        let (tx, rx) = mpsc::channel();
+        let mut pending = LineBuffer::default();
+        let chunk = vec![b'x'; MAX_LINE_BYTES / 2 + 1];
+        // no newline for three chunks: the line goes out once it passes the limit, the rest is dropped
+        for _ in 0..3 {
+            assert!(pending.emit(&chunk, &None, &[], &tx));
+        }
+        assert!(pending.emit(b"xxx\nLogTemp: next\n", &None, &[], &tx));
+        let lines = lines(&rx);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].len() < MAX_LINE_BYTES + 32);
+        assert!(lines[0].ends_with("(line truncated)"));
+        assert_eq!(lines[1], "LogTemp: next");
+    }
+
+    #[test]
+    fn reads_build_info_from_startup_lines() {
+        let mut info = BuildInfo::default();
+        assert_eq!(info.summary(), None);
+        assert!(info.update("[2024.01.31-10.30.00:000][  0]LogInit: Engine Version: 5.3.2-29314046+++UE5+Release-5.3"));
+        assert!(!info.update("LogInit: Compatible Engine Version: 5.3.0-27405482+++UE5+Release-5.3"));
+        assert!(info.update("LogInit: Build Configuration: Development\r"));
+        assert!(info.update("LogInit: Branch Name: ++UE5+Release-5.3"));
+        assert!(!info.update("LogTemp: Branch Name: nope"));
+        assert_eq!(info.summary().as_deref(), Some("UE 5.3.2, Development, branch ++UE5+Release-5.3"));
+    }
+
+    #[test]
+    fn normalizes_crlf_split_across_reads_and_lone_cr() {
+        let (tx, rx) = mpsc::channel();
+        let mut pending = LineBuffer::default();
+        let chunks: [&[u8]; 6] = [b"LogTemp: one\r", b"\nLogTemp: tw", b"o\r\n\r", b"\nLogTemp: 10%\rLogTemp: 5", b"0%\rLogTemp: 100%\r", b"\r\nLogTemp: done\n"];
+        for chunk in chunks {
+            assert!(pending.emit(chunk, &None, &[], &tx));
+        }
+        let lines = lines(&rx);
+        assert_eq!(lines, ["LogTemp: one", "LogTemp: two", "LogTemp: 100%", "LogTemp: done"]);
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn decodes_utf16_split_inside_code_units() {
+        let (tx, rx) = mpsc::channel();
+        let mut pending = LineBuffer::default();
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend("LogTemp: caffè\r\nLogTemp: 🎮 ok\r\n".encode_utf16().flat_map(u16::to_le_bytes));
+        pending.detect_encoding(&bytes);
+        // odd-sized reads cut code units and the surrogate pair in half
+        for chunk in bytes.chunks(3) {
+            assert!(pending.emit(chunk, &None, &[], &tx));
+        }
+        let lines = lines(&rx);
+        assert_eq!(lines, ["LogTemp: caffè", "LogTemp: 🎮 ok"]);
+
+        let mut plain = LineBuffer::default();
+        plain.detect_encoding(b"[2024");
+        assert_eq!(plain.unit(), 1);
+    }
+
+    #[test]
+    fn tails_exit_on_stop() {
+        let dir = std::env::temp_dir().join(format!("ue-tui-stop-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (tx, _rx) = mpsc::channel();
+        let tails: Vec<_> = (0..4).map(|i| {
+            let path = dir.join(format!("Game{}.log", i));
+            fs::write(&path, "LogTemp: hello\n").unwrap();
+            let (tx_cmd, rx_cmd) = mpsc::channel();
+            let opts = TailOptions { poll: PollInterval::Fixed(Duration::from_millis(10)), ..TailOptions::default() };
+            (tx_cmd, spawn_source(LogSource::File { path, robust: false }, opts, tx.clone(), rx_cmd))
+        }).collect();
+        for (tx_cmd, _) in &tails {
+            tx_cmd.send(Cmd::Stop).unwrap();
+        }
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        for (_, handle) in tails {
+            while !handle.is_finished() && std::time::Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(5));
+            }
+            assert!(handle.is_finished(), "tail thread still running after Stop");
+            handle.join().unwrap();
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unreadable_log_still_polls() {
+        // A directory has a size but every read fails; the tail must still reach its sleep and report
+        let dir = std::env::temp_dir().join(format!("ue-tui-unreadable-{}", std::process::id()));
+        fs::create_dir_all(dir.join("Game.log")).unwrap();
+        fs::write(dir.join("Game.log").join("x"), "x").unwrap();
+        let (tx, rx) = mpsc::channel();
+        let (tx_cmd, rx_cmd) = mpsc::channel();
+        let opts = TailOptions { from_start: true, poll: PollInterval::Fixed(Duration::from_millis(10)), ..TailOptions::default() };
+        let handle = spawn_source(LogSource::File { path: dir.join("Game.log"), robust: false }, opts, tx, rx_cmd);
+        loop {
+            match rx.recv_timeout(Duration::from_secs(5)).expect("tail never finished a poll") {
+                TailEvent::BacklogDone => break,
+                _ => continue,
+            }
+        }
+        tx_cmd.send(Cmd::Stop).unwrap();
+        handle.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn follows_a_log_renamed_away_and_recreated() {
+        let dir = std::env::temp_dir().join(format!("ue-tui-rotate-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Game.log");
+        fs::write(&path, "LogTemp: old session\n").unwrap();
+        let (tx, rx) = mpsc::channel();
+        let (tx_cmd, rx_cmd) = mpsc::channel();
+        let opts = TailOptions { from_start: true, poll: PollInterval::Fixed(Duration::from_millis(10)), ..TailOptions::default() };
+        let handle = spawn_source(LogSource::File { path: path.clone(), robust: false }, opts, tx, rx_cmd);
+        let next_line = || loop {
+            match rx.recv_timeout(Duration::from_secs(5)).expect("no line within 5s") {
+                TailEvent::Line(l) => break l.text,
+                _ => continue,
+            }
+        };
+        assert_eq!(next_line(), "LogTemp: old session");
+        // what the editor does on startup: the old log becomes a backup, a new one starts at the same path.
+        // It is longer than the old one, so only noticing the new file (not a shrink) reads it from the start
+        fs::rename(&path, dir.join("Game-backup-2024.01.31-10.00.00.log")).unwrap();
+        fs::write(&path, "LogTemp: new session, first line\nLogTemp: second line\n").unwrap();
+        assert_eq!(next_line(), "LogTemp: new session, first line");
+        assert_eq!(next_line(), "LogTemp: second line");
+        tx_cmd.send(Cmd::Stop).unwrap();
+        handle.join().unwrap();
+        // nothing of the old session comes around again
+        assert!(!rx.try_iter().any(|e| matches!(e, TailEvent::Line(_))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,9 +1,17 @@
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet, VecDeque},
     fs::{self, File},
-    io::{Read, Seek, SeekFrom},
+    hash::{Hash, Hasher},
+    io::Write,
     path::{Path, PathBuf},
-    sync::mpsc,
+    rc::Rc,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, OnceLock,
+    },
     thread,
     time::{Duration, Instant},
 };
@@ -16,18 +24,144 @@ use crossterm::{
 };
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, Paragraph, Gauge, Clear},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Gauge, Clear, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use ue_tui::{
+    parse_cook_progress_line, parse_shader_progress_line, spawn_source,
+    BuildInfo, Cmd, ColorRule, LineColor, LogLine, LogSource, Matcher, PollInterval, RecordedLine, TailEvent, TailOptions, Verbosity,
 };
-use serde::Deserialize;
 
 /* ------------------------- Config structures ------------------------- */
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 struct Config {
+    #[serde(default)]
     projects: Vec<Project>,
     #[serde(default)]
     builds: Vec<Build>,
+    #[serde(default)]
+    remotes: Vec<Remote>,
+    #[serde(default)]
+    colors: Vec<ColorRuleConfig>,
+    #[serde(default)]
+    max_lines: Option<usize>,  // scrollback cap per target (default 20000)
+    #[serde(default)]
+    notifications: NotificationConfig,
+    #[serde(default)]
+    highlights: Vec<HighlightConfig>,
+    #[serde(default)]
+    poll_interval_ms: Option<u64>, // how often tail threads check the file (default 150)
+    #[serde(default)]
+    adaptive_poll: bool,       // poll fast after new data, back off while the file is idle
+    #[serde(default)]
+    open_command: Option<String>, // editor for file(line) references, e.g. "code -g {file}:{line}"
+    #[serde(default)]
+    wrap_indent: Option<bool>, // indent wrapped rows under the message (default true)
+    #[serde(default)]
+    timestamp_format: Option<String>, // "full" (default), "time", "time-ms" or a strftime pattern
+    #[serde(default)]
+    ansi_colors: Option<bool>, // paint ANSI color codes found in the log (default true); false just strips them
+    #[serde(default)]
+    severity_bands: Option<bool>, // start with error/warning rows on a tinted background (default false)
+    #[serde(default)]
+    category_palette: Vec<String>, // colors categories are spread over by name (default: a built-in set)
+    #[serde(default)]
+    hidden_categories: Vec<String>, // never shown unless picked explicitly or revealed with Shift+F
+    #[serde(default)]
+    filter_presets: Vec<FilterPresetConfig>, // category/verbosity/regex filters recalled with F1-F12
+    #[serde(default)]
+    theme: ThemeConfig,        // colors of error/warning/... lines, categories, headers; see Theme
+    #[serde(default)]
+    progress: Vec<ProgressParserConfig>, // "X of Y" lines of other tools, shown as a header gauge like the cook
+    #[serde(default)]
+    group_multiline: Option<bool>, // attach lines without timestamp/category to the line above (default true)
+    #[serde(default)]
+    stop_following_on: Option<String>, // verbosity ("Error", "Warning", ...) that stops following a few rows after it
+    #[serde(default)]
+    page_lines: Option<usize>, // fixed PageUp/PageDown step; default is a screenful minus a small overlap
+    #[serde(default)]
+    max_width: Option<u16>,    // widest log text in columns; a wider terminal centers the body with margins
+    #[serde(default)]
+    bell: Option<String>,      // "audible" or "visual": alert on an error line while following (default off)
+    #[serde(default)]
+    low_disk_gb: Option<f64>,  // during a cook, warn below this much free space on the log's volume (default 10)
+}
+impl Config {
+    // Entries in the selection list: projects, then builds, then remotes
+    fn target_count(&self) -> usize {
+        self.projects.len() + self.builds.len() + self.remotes.len()
+    }
+
+    // (title, key) of every entry in list order; the menu filter matches both
+    fn target_labels(&self) -> Vec<(String, &str)> {
+        self.projects.iter().map(|p| (p.name_or_key(), p.key.as_str()))
+            .chain(self.builds.iter().map(|b| (b.name_or_key(), b.key.as_str())))
+            .chain(self.remotes.iter().map(|r| (r.name_or_key(), r.key.as_str())))
+            .collect()
+    }
+
+    // strftime pattern for parsed timestamps; None shows Unreal's raw string
+    fn timestamp_pattern(&self) -> Option<&str> {
+        match self.timestamp_format.as_deref() {
+            None | Some("full") => None,
+            Some("time") => Some("%H:%M:%S"),
+            Some("time-ms") => Some("%H:%M:%S%.3f"),
+            Some(custom) => Some(custom),
+        }
+    }
+
+    fn stop_following_on(&self) -> Option<Verbosity> {
+        self.stop_following_on.as_deref().and_then(Verbosity::parse)
+    }
+
+    // "audible" rings the terminal bell, "visual" flashes the log border
+    fn bell(&self) -> Option<Bell> {
+        match self.bell.as_deref() {
+            Some("audible") => Some(Bell::Audible),
+            Some("visual") => Some(Bell::Visual),
+            _ => None,
+        }
+    }
+
+    // Configured palette, or the built-in one when unset
+    fn category_palette(&self) -> Vec<Color> {
+        let configured: Vec<Color> = self.category_palette.iter().filter_map(|c| Color::from_str(c).ok()).collect();
+        if configured.is_empty() { DEFAULT_CATEGORY_PALETTE.to_vec() } else { configured }
+    }
+
+    fn poll_interval(&self) -> PollInterval {
+        if self.adaptive_poll {
+            PollInterval::Adaptive
+        } else {
+            PollInterval::Fixed(Duration::from_millis(self.poll_interval_ms.unwrap_or(150).max(1)))
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bell {
+    Audible,
+    Visual,
+}
+
+// Desktop notifications; only delivered when built with `--features notifications`
+#[derive(Debug, Deserialize)]
+struct NotificationConfig {
+    #[serde(default = "default_true")]
+    cook_complete: bool,       // notify when a cook finishes
+    #[serde(default)]
+    error_spike: Option<usize>, // notify when more than N errors arrive within the window
+    #[serde(default = "default_error_window_secs")]
+    error_window_secs: u64,
+}
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self { cook_complete: true, error_spike: None, error_window_secs: default_error_window_secs() }
+    }
 }
+fn default_true() -> bool { true }
+fn default_error_window_secs() -> u64 { 10 }
 #[derive(Debug, Clone, Deserialize)]
 struct Project {
     key: String,               // e.g. "prj1" or "prj2"
@@ -35,7 +169,21 @@ struct Project {
     name: String,              // pretty name
     uproject: PathBuf,         // absolute or relative path to .uproject
     #[serde(default)]
+    log_path: Option<PathBuf>, // explicit log file, used verbatim instead of the derived one
+    #[serde(default)]
+    extra_logs: Vec<PathBuf>,  // more files tailed with the main log (crash reports, ...), each with its own source tag
+    #[serde(default)]
+    all_logs: bool,            // also tail every other `*.log` next to the main log, as found when opening
+    #[serde(default)]
+    color: Option<String>,     // accent for the title and header; same forms as ColorRuleConfig::color
+    #[serde(default)]
+    robust_polling: bool,      // for network drives: re-read instead of trusting cached size/mtime
+    #[serde(default)]
     discovered: bool,          // true if auto-discovered from running editor
+    #[serde(skip)]
+    started: Option<u64>,      // editor process start (seconds since epoch), discovered only
+    #[serde(skip)]
+    running: bool,             // an editor had this .uproject open at the last discovery pass
 }
 #[derive(Debug, Clone, Deserialize)]
 struct Build {
@@ -43,60 +191,621 @@ struct Build {
     #[serde(default)]
     name: String,              // pretty name
     exe: PathBuf,              // absolute or relative path to .exe
+    #[serde(default)]
+    log_path: Option<PathBuf>, // explicit log file, used verbatim instead of the derived one
+    #[serde(default)]
+    color: Option<String>,     // accent for the title and header
+    #[serde(default)]
+    robust_polling: bool,      // as for projects
+    #[serde(default)]
+    launch: bool,              // run the exe on open and merge its stdout/stderr into the view
+}
+// Log streamed from a shell command instead of a local file, e.g. "ssh buildbox tail -F /ue/Saved/Logs/Game.log"
+#[derive(Debug, Clone, Deserialize)]
+struct Remote {
+    key: String,
+    #[serde(default)]
+    name: String,              // pretty name
+    command: String,           // run through the platform shell; each stdout line is a log line
+    #[serde(default)]
+    color: Option<String>,     // accent for the title and header
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ColorRuleConfig {
+    #[serde(default)]
+    contains: Option<String>,  // case-insensitive substring
+    #[serde(default)]
+    regex: Option<String>,     // alternative to `contains`
+    color: String,             // "red", "brightblue", "#RRGGBB", 0-255 index
+}
+
+// Keyword painted wherever it appears, on top of the line color
+#[derive(Debug, Clone, Deserialize)]
+struct HighlightConfig {
+    pattern: String,           // regex, e.g. "UE-\\d+" or "(?i)niagara"
+    color: String,             // same forms as ColorRuleConfig::color
+}
+#[derive(Debug)]
+struct Highlight {
+    regex: regex::Regex,
+    color: Color,
+}
+
+// Filter set bound to a function key; pressing it replaces the category, verbosity and regex filters
+#[derive(Debug, Clone, Deserialize)]
+struct FilterPresetConfig {
+    name: String,              // shown in the header while the preset's filters are in place
+    key: String,               // "F1" .. "F12"
+    #[serde(default)]
+    categories: Vec<String>,   // exact names, e.g. "LogRenderer"
+    #[serde(default)]
+    min_verbosity: Option<String>, // "Error", "Warning", ... as with v/V
+    #[serde(default)]
+    regex: Option<String>,     // same as the r filter: tried on category and message
+}
+// A built-in theme by name plus per-role overrides. Each value is a color, optionally preceded by modifiers
+// ("bold", "dim", "italic", "underlined", "reversed") and/or followed by "on <color>" for the background
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct ThemeConfig {
+    base: Option<String>,      // "default", "colorblind" or "monochrome"
+    fatal: Option<String>,
+    error: Option<String>,
+    warning: Option<String>,
+    info: Option<String>,      // Display/Log lines
+    verbose: Option<String>,
+    category: Option<String>,  // one style for every category instead of the per-name palette
+    timestamp: Option<String>,
+    header_project: Option<String>, // default accent of projects, builds and remotes without a `color`
+    header_build: Option<String>,
+    header_remote: Option<String>,
+    highlight: Option<String>, // search matches
+    accent: Option<String>,    // selection marks, "● running", the cook gauge
+}
+
+// Progress line of some tool (DDC fill, asset validation, ...): a regex with `current` and `total` groups
+#[derive(Debug, Clone, Deserialize)]
+struct ProgressParserConfig {
+    label: String,             // gauge caption, e.g. "DDC"
+    regex: String,             // e.g. "Filling DDC: (?P<current>\\d+) of (?P<total>\\d+)"
+    #[serde(default)]
+    color: Option<String>,     // gauge color (default cyan)
+}
+#[derive(Debug)]
+struct ProgressParser {
+    label: String,
+    regex: regex::Regex,
+    color: Color,
+}
+
+#[derive(Debug)]
+struct FilterPreset {
+    name: String,
+    key: u8,
+    categories: HashSet<String>,
+    min_verbosity: Option<Verbosity>,
+    regex: Option<regex::Regex>,
+}
+
+// Window options remembered across runs in .unreal-tui-state.json (next to the config)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
+struct UiState {
+    selected: usize,
+    show_timestamp: bool,
+    wrap_lines: bool,
+    show_line_numbers: bool,
+    show_header: bool,
+    show_footer: bool,
+    search_history: Vec<String>,           // oldest first
+    filter_history: Vec<String>,
+    path_style: PathStyle,
+    targets: BTreeMap<String, TargetFilters>, // by target key ("a+b" for a merged view)
+}
+impl Default for UiState {
+    fn default() -> Self {
+        Self {
+            selected: 0,
+            show_timestamp: false,
+            wrap_lines: true,
+            show_line_numbers: false,
+            show_header: true,
+            show_footer: true,
+            search_history: Vec::new(),
+            filter_history: Vec::new(),
+            path_style: PathStyle::Full,
+            targets: BTreeMap::new(),
+        }
+    }
+}
+
+// Filters a target was left with, put back when it is opened again (Ctrl+F forgets them)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct TargetFilters {
+    categories: Vec<String>,               // sorted
+    min_verbosity: Option<String>,
+    regex: Option<String>,
+    collapse_duplicates: bool,
+    show_hidden: bool,
 }
 
 /* --------------------------- App structures -------------------------- */
 
+// How the selection list shows uproject/exe paths; P cycles through them
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum PathStyle {
+    Full,           // as configured
+    Home,           // home directory replaced by ~
+    Folder,         // parent folder and file name only
+}
+impl PathStyle {
+    fn next(self) -> Self {
+        match self {
+            PathStyle::Full => PathStyle::Home,
+            PathStyle::Home => PathStyle::Folder,
+            PathStyle::Folder => PathStyle::Full,
+        }
+    }
+}
+
 #[derive(PartialEq)]
 enum Mode {
     Select,         // choose a project
     View,           // show tail of log
 }
 
-#[derive(Clone)]
-struct LogLine {
-    // original full line as read
+// Single-line input shown in the footer while the user types
+#[derive(Clone, Copy, PartialEq)]
+enum Prompt {
+    Search,         // '/' incremental text search
+    Regex,          // 'r' regex filter on category or full text
+    TimeRange,      // 'J' keep lines between two timestamps
+    JumpTime,       // 'j' scroll to the first line at or after a timestamp
+}
+
+// A point typed in the time prompts: a time of day matches any date, a full timestamp only itself
+#[derive(Clone, Copy, PartialEq)]
+enum TimePoint {
+    Clock(chrono::NaiveTime),
+    At(chrono::NaiveDateTime),
+}
+impl TimePoint {
+    // "10:30", "10:30:15.250", "2024.01.31-10.30.15" (Unreal) or "2024-01-31 10:30[:15]"
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        for f in ["%H:%M:%S%.f", "%H:%M"] {
+            if let Ok(t) = chrono::NaiveTime::parse_from_str(s, f) { return Some(TimePoint::Clock(t)); }
+        }
+        for f in ["%Y.%m.%d-%H.%M.%S:%3f", "%Y.%m.%d-%H.%M.%S", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%d %H:%M"] {
+            if let Ok(t) = chrono::NaiveDateTime::parse_from_str(s, f) { return Some(TimePoint::At(t)); }
+        }
+        None
+    }
+
+    fn cmp_to(&self, dt: &chrono::NaiveDateTime) -> std::cmp::Ordering {
+        match self {
+            TimePoint::Clock(t) => t.cmp(&dt.time()),
+            TimePoint::At(t) => t.cmp(dt),
+        }
+    }
+}
+impl std::fmt::Display for TimePoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimePoint::Clock(t) => write!(f, "{}", t.format("%H:%M:%S%.f")),
+            TimePoint::At(t) => write!(f, "{}", t.format("%Y.%m.%d-%H.%M.%S")),
+        }
+    }
+}
+
+// "start" or "start..end", both inclusive
+fn parse_time_range(s: &str) -> Option<(TimePoint, Option<TimePoint>)> {
+    match s.split_once("..") {
+        Some((start, end)) if !end.trim().is_empty() => Some((TimePoint::parse(start)?, Some(TimePoint::parse(end)?))),
+        Some((start, _)) => Some((TimePoint::parse(start)?, None)),
+        None => Some((TimePoint::parse(s)?, None)),
+    }
+}
+
+// A vim-style count and/or `g` waiting for the key that completes the motion
+#[derive(Clone, Copy)]
+struct PendingMotion {
+    count: usize,   // 0: none typed
+    g: bool,        // first half of `gg`
+    since: Instant,
+}
+
+// A lone digit left this long opens that target instead (1-9 outside of counts); any key that isn't
+// part of a motion opens it at once
+const PENDING_MOTION_TIMEOUT: Duration = Duration::from_millis(800);
+
+struct InputLine {
+    prompt: Prompt,
+    buffer: String,
+    history_pos: Option<usize>, // entry shown while browsing with Up/Down
+    draft: String,              // what was typed before browsing started
+}
+impl InputLine {
+    fn new(prompt: Prompt, buffer: String) -> Self {
+        Self { prompt, buffer, history_pos: None, draft: String::new() }
+    }
+
+    // Up/Down through earlier entries like a shell; stepping past the newest restores the draft
+    fn recall(&mut self, history: &[String], older: bool) -> bool {
+        let pos = match (self.history_pos, older) {
+            (None, true) if !history.is_empty() => {
+                self.draft = self.buffer.clone();
+                history.len() - 1
+            }
+            (Some(p), true) if p > 0 => p - 1,
+            (Some(p), false) if p + 1 < history.len() => p + 1,
+            (Some(_), false) => {
+                self.history_pos = None;
+                self.buffer = std::mem::take(&mut self.draft);
+                return true;
+            }
+            _ => return false,
+        };
+        self.history_pos = Some(pos);
+        self.buffer = history[pos].clone();
+        true
+    }
+}
+
+// Entries kept per prompt in the state file
+const HISTORY_LIMIT: usize = 50;
+
+fn push_history(history: &mut Vec<String>, entry: &str) {
+    if history.last().map(String::as_str) == Some(entry) { return; }
+    history.push(entry.to_string());
+    if history.len() > HISTORY_LIMIT {
+        history.drain(..history.len() - HISTORY_LIMIT);
+    }
+}
+
+// Search text plus the prompt toggles, compiled once for matching and highlighting alike
+struct SearchQuery {
     text: String,
-    color: Color,
-    // parsed pieces for richer rendering
-    ts: Option<String>,           // content of first [ ... ]
-    category: Option<String>,     // e.g., LogRenderer
-    message: String,              // remainder after category and colon
+    ignore_case: bool,
+    whole_word: bool,
+    regex: regex::Regex,
+}
+impl SearchQuery {
+    fn new(text: String, ignore_case: bool, whole_word: bool) -> Self {
+        let mut pattern = regex::escape(&text);
+        if whole_word {
+            pattern = format!(r"\b{}\b", pattern);
+        }
+        let regex = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(ignore_case)
+            .build()
+            .expect("escaped search text is a valid regex");
+        Self { text, ignore_case, whole_word, regex }
+    }
+
+    // Short indicators shown next to the match count
+    fn flags(&self) -> String {
+        let case = if self.ignore_case { "[aA]" } else { "" };
+        let word = if self.whole_word { "[word]" } else { "" };
+        format!("{}{}", case, word)
+    }
+}
+
+// Latest match of a configured progress parser; hidden once complete or idle for PROGRESS_IDLE
+struct Progress {
+    parser: usize,             // index into App::progress_parsers
+    current: u64,
+    total: u64,
+    updated: Instant,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum LiveCoding {
+    Compiling,
+    Failed,                    // stays visible until the next compile starts
+}
+
+// Snapshot of line counts per category, taken when the stats popup opens; E saves it as JSON
+#[derive(Serialize)]
+struct Stats {
+    #[serde(rename = "categories")]
+    rows: Vec<StatRow>,        // sorted by line count, descending
+    lines: usize,
+    errors: usize,
+    warnings: usize,
+    hidden: usize,             // lines held back by `hidden_categories`
+    levels: LevelCounts,
+    first: Option<String>,     // earliest and latest parsed timestamps in the buffer
+    last: Option<String>,
+    duration_secs: Option<i64>,
+}
+#[derive(Serialize)]
+struct StatRow {
+    category: String,
+    lines: usize,
+    errors: usize,
+    warnings: usize,
+    levels: LevelCounts,
+}
+
+// Lines per verbosity in Verbosity::ALL order; lines without one count as Log, as in the verbosity filter
+#[derive(Default, Clone, Copy)]
+struct LevelCounts([usize; 6]);
+impl LevelCounts {
+    fn add(&mut self, level: Option<Verbosity>) {
+        let level = level.unwrap_or(Verbosity::Log);
+        if let Some(i) = Verbosity::ALL.iter().position(|&v| v == level) { self.0[i] += 1; }
+    }
+}
+impl Serialize for LevelCounts {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (level, count) in Verbosity::ALL.iter().zip(self.0) {
+            map.serialize_entry(level.as_str(), &count)?;
+        }
+        map.end()
+    }
+}
+
+// A body row as drawn: the index of its line and which wrapped row of that line it is
+type RowRef = (usize, usize);
+
+// Second pane of the split view. Its state is swapped with the App's own category filter and
+// scroll offset when focus moves, so keys and mouse always act on the focused pane.
+struct SplitPane {
+    categories: HashSet<String>,
+    scroll_from_bottom: usize,
+    focus_second: bool,        // the focused pane is the right one
+    area: Rect,                // where the unfocused pane was drawn, for mouse hit testing
+    minimap_area: Rect,
+    filter_cache: RefCell<FilterCache>, // each pane filters differently, so each keeps its own caches
+    row_cache: Option<(u64, Vec<Line<'static>>, Vec<RowRef>)>,
+    row_lines: Vec<RowRef>,
+}
+
+impl SplitPane {
+    fn new() -> Self {
+        Self {
+            categories: HashSet::new(),
+            scroll_from_bottom: 0,
+            focus_second: false,
+            area: Rect::new(0, 0, 0, 0),
+            minimap_area: Rect::new(0, 0, 0, 0),
+            filter_cache: RefCell::default(),
+            row_cache: None,
+            row_lines: Vec::new(),
+        }
+    }
+
+    fn swap_with(&mut self, app: &mut App) {
+        std::mem::swap(&mut app.active_categories, &mut self.categories);
+        std::mem::swap(&mut app.scroll_from_bottom, &mut self.scroll_from_bottom);
+        std::mem::swap(&mut app.last_body_area, &mut self.area);
+        std::mem::swap(&mut app.last_minimap_area, &mut self.minimap_area);
+        app.filter_cache.swap(&self.filter_cache);
+        std::mem::swap(&mut app.row_cache, &mut self.row_cache);
+        std::mem::swap(&mut app.last_row_lines, &mut self.row_lines);
+    }
+}
+
+// Everything passes_filters and the duplicate folding look at; a change means a full re-scan
+#[derive(Clone, PartialEq)]
+struct FilterKey {
+    source: Option<String>,
+    categories: HashSet<String>,
+    min_verbosity: Option<Verbosity>,
+    regex: Option<String>,
+    time: Option<(TimePoint, Option<TimePoint>)>,
+    hidden: HashSet<String>, // empty while Shift+F shows them
+    collapse: bool,
+}
+
+// filtered_indices() result: extended with the lines that arrived since, re-based after eviction,
+// and rebuilt only when the filters change or `lines` is rewritten
+#[derive(Default)]
+struct FilterCache {
+    key: Option<FilterKey>,
+    epoch: u64,              // App::lines_epoch it was built for
+    dropped: usize,          // App::lines_dropped when last brought up to date
+    scanned: usize,          // lines looked at so far, counted like `dropped`
+    indices: Rc<Vec<usize>>,
+    generation: u64,         // bumped on every rebuild, part of the row cache key
+}
+
+// Keyboard category picker: distinct categories seen so far, collected when it opens
+struct CategoryPicker {
+    rows: Vec<(String, usize)>, // (category, line count), sorted by name
+    selected: usize,
+}
+
+// A selection-list entry resolved to its log
+struct Target {
+    key: String,
+    name: String,
+    log: LogSource,
+    is_build: bool,
+    accent: Color,
+    root: Option<PathBuf>,     // project/exe directory, for resolving relative source paths
+    launch: Option<PathBuf>,   // program started alongside the log tail
+    extra_logs: Vec<(String, LogSource)>, // further files of the project, by file stem
+    siblings: Option<SiblingScan>, // `all_logs`: keep looking for new logs in the folder
 }
 
-enum Cmd {
-    Clear,          // jump tail offset to EOF
+// `all_logs` folder of an open project: logs that show up there later (a crash report, ...) join the view
+struct SiblingScan {
+    main: PathBuf,
+    key: String,
+    robust: bool,
+    known: Vec<PathBuf>,       // already tailed, the main log included
 }
 
-enum AppEvent {
-    Line(LogLine),
-    Error(String),
-    Tick,
+// A target that is not on screen but keeps tailing in the background
+struct TargetState {
+    lines: Vec<LogLine>,
+    lines_dropped: usize,
+    sibling_scans: Vec<SiblingScan>,
+    tx_events: Option<mpsc::Sender<TailEvent>>,
+    queued: Arc<AtomicUsize>,
+    scroll_from_bottom: usize,
+    tail_status: HashMap<PathBuf, Option<u64>>,
+    rx: mpsc::Receiver<TailEvent>,
+    tx_cmds: Vec<mpsc::Sender<Cmd>>,
 }
 
+// Cook ETA: rate measured over this window; no estimate once the count stops moving this long
+const COOK_ETA_WINDOW: Duration = Duration::from_secs(60);
+const COOK_STALL_AFTER: Duration = Duration::from_secs(30);
+
+// A configured progress gauge that stops moving is dropped after this long (its tool may not say it finished)
+const PROGRESS_IDLE: Duration = Duration::from_secs(30);
+
+// Two left clicks on the same cell within this count as a double-click (copies the line)
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+// With the footer hidden, a new status message is overlaid on the body this long
+const TOAST_FOR: Duration = Duration::from_secs(3);
+
+// stop_following_on: rows still followed below the severe line before the view holds
+const ERROR_STOP_ROWS: usize = 3;
+
+// `bell`: at most one alert this often, however many errors a burst brings; a visual one lasts FLASH_FOR
+const BELL_EVERY: Duration = Duration::from_secs(5);
+const FLASH_FOR: Duration = Duration::from_millis(300);
+
+// To keep UI responsive when a lot of lines arrive, process at most this many events per tick; a view
+// further behind than that shows it in the footer
+const MAX_EVENTS_PER_TICK: usize = 1000;
+
+// cap memory – keep last 20k lines per target unless `max_lines` says otherwise
+const DEFAULT_MAX_LINES: usize = 20_000;
+
 /* ------------------------------ Main -------------------------------- */
 
 fn main() -> Result<()> {
+    // Optional flags plus a positional log file to watch directly
+    let mut cli_log: Option<PathBuf> = None;
+    let mut from_start = false;
+    let mut no_mouse = false;
+    let mut alt_screen = true;
+    let mut record: Option<PathBuf> = None;
+    let mut replay: Option<PathBuf> = None;
+    let mut replay_fast = false;
+    let mut args = std::env::args_os().skip(1);
+    while let Some(a) = args.next() {
+        if a == "-h" || a == "--help" {
+            println!("Usage: ue-tui [--from-start] [--no-mouse] [--no-alt-screen] [--record FILE] [--replay FILE [--fast]] [LOG_FILE]");
+            println!();
+            println!("Without arguments, pick a project or build from projects.json.");
+            println!("With LOG_FILE, tail that file directly (it may not exist yet).");
+            println!();
+            println!("  --from-start     Load the existing log content before following new lines");
+            println!("  --no-mouse       Leave the mouse to the terminal (native text selection, screen readers)");
+            println!("  --no-alt-screen  Draw in the normal screen, so the last frame stays in the scrollback on exit");
+            println!("  --record FILE    Append every received line, with its parsed fields, to FILE (JSON Lines)");
+            println!("  --replay FILE    Show a recorded session instead of tailing, at its original pace");
+            println!("  --fast           With --replay, feed the lines as fast as possible");
+            return Ok(());
+        } else if a == "--from-start" {
+            from_start = true;
+        } else if a == "--no-mouse" {
+            no_mouse = true;
+        } else if a == "--no-alt-screen" {
+            alt_screen = false;
+        } else if a == "--record" || a == "--replay" {
+            let Some(file) = args.next() else {
+                return Err(anyhow!("{} needs a file name", a.to_string_lossy()));
+            };
+            if a == "--record" { record = Some(PathBuf::from(file)); } else { replay = Some(PathBuf::from(file)); }
+        } else if a == "--fast" {
+            replay_fast = true;
+        } else {
+            cli_log = Some(PathBuf::from(a));
+        }
+    }
+
     // Load config before touching the terminal.
-    let mut cfg = load_config().context("Cannot load projects.json")?;
+    let (mut cfg, config_paths) = load_config().context("Cannot load the projects config")?;
+    let color_rules = compile_color_rules(&cfg.colors).context("Invalid \"colors\" rule in the projects config")?;
+    let highlights = compile_highlights(&cfg.highlights).context("Invalid \"highlights\" entry in the projects config")?;
+    let presets = compile_filter_presets(&cfg.filter_presets).context("Invalid \"filter_presets\" entry in the projects config")?;
+    let progress = compile_progress_parsers(&cfg.progress).context("Invalid \"progress\" entry in the projects config")?;
     // Merge auto-discovered editors before starting UI
     merge_discovered_into_config(&mut cfg);
+    if let Some(path) = replay.as_ref().filter(|p| !p.is_file()) {
+        return Err(anyhow!("Replay file not found: {}", path.display()));
+    }
+    let recorder = match &record {
+        Some(path) => Some(std::io::LineWriter::new(
+            fs::OpenOptions::new().create(true).append(true).open(path)
+                .with_context(|| format!("Cannot open {} for recording", path.display()))?,
+        )),
+        None => None,
+    };
+
+    // A panic in the UI thread would leave the shell in raw mode; put the terminal back before the
+    // default hook prints the message. Worker threads panicking don't take the UI down, so they're left alone
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if std::thread::current().name() == Some("main") {
+            let _ = disable_raw_mode();
+            let _ = execute!(std::io::stdout(), DisableMouseCapture, crossterm::cursor::Show);
+            if alt_screen {
+                let _ = execute!(std::io::stdout(), LeaveAlternateScreen);
+            }
+        }
+        default_hook(info);
+    }));
 
     // Terminal init
     enable_raw_mode()?;
     let mut stdout = std::io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    if alt_screen {
+        execute!(stdout, EnterAlternateScreen)?;
+    }
+    let mut mouse_captured = !no_mouse;
+    if mouse_captured {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = ratatui::prelude::CrosstermBackend::new(stdout);
     let mut terminal = ratatui::Terminal::new(backend)?;
+    if !alt_screen {
+        // scroll what is on screen into the scrollback instead of drawing over it
+        let rows = terminal.size()?.height;
+        terminal.backend_mut().write_all("\n".repeat(rows as usize).as_bytes())?;
+        terminal.clear()?;
+    }
 
-    let mut app = App::new(cfg);
+    let mut app = App::new(cfg, color_rules, highlights, presets, progress, config_paths);
+    app.tail_from_start = from_start;
+    app.mouse_enabled = mouse_captured;
+    app.recorder = recorder;
+    if let Some(path) = replay {
+        app.open_replay(path, replay_fast)?;
+    } else if let Some(path) = cli_log {
+        app.open_log_file(path)?;
+    }
 
     // UI/Event loop
     let tick_rate = Duration::from_millis(100);
     let mut last_tick = Instant::now();
 
     loop {
-        terminal.draw(|f| app.draw(f))?;
+        // M toggles mouse capture at runtime
+        if app.mouse_enabled != mouse_captured {
+            mouse_captured = app.mouse_enabled;
+            if mouse_captured {
+                execute!(terminal.backend_mut(), EnableMouseCapture)?;
+            } else {
+                execute!(terminal.backend_mut(), DisableMouseCapture)?;
+            }
+        }
+        terminal.draw(|f| {
+            app.draw(f);
+            if app.no_color { strip_colors(f.buffer_mut()); }
+        })?;
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
@@ -115,33 +824,70 @@ fn main() -> Result<()> {
             }
         }
 
+        // Terminal editors need the terminal: suspend the UI while $EDITOR runs
+        if let Some((path, line)) = app.pending_editor.take() {
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), DisableMouseCapture)?;
+            if alt_screen {
+                execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+            }
+            let status = run_editor(&path, line);
+            enable_raw_mode()?;
+            if alt_screen {
+                execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+            }
+            if mouse_captured {
+                execute!(terminal.backend_mut(), EnableMouseCapture)?;
+            }
+            terminal.clear()?;
+            if let Err(e) = status {
+                app.last_error = Some(format!("Cannot run $EDITOR: {:#}", e));
+            }
+        }
+
+        // Mark where unread lines begin once the user scrolls away from the bottom
+        app.update_seen_marker();
+        app.expire_pending_motion()?;
+
         // Ticks + log lines
         if last_tick.elapsed() >= tick_rate {
             last_tick = Instant::now();
-            // To keep UI responsive when a lot of lines arrive, process at most a budget per tick
-            const MAX_EVENTS_PER_TICK: usize = 1000;
+            // A backlog load gets a bigger budget but still spreads over several ticks
+            const MAX_BACKLOG_EVENTS_PER_TICK: usize = 20_000;
+            let loading = app.backlog_pending > 0;
+            let budget = if loading { MAX_BACKLOG_EVENTS_PER_TICK } else { MAX_EVENTS_PER_TICK };
             let mut processed = 0usize;
-            while processed < MAX_EVENTS_PER_TICK {
+            while processed < budget {
                 match app.rx.try_recv() {
-                    Ok(AppEvent::Line(l)) => { app.push_line(l); processed += 1; }
-                    Ok(AppEvent::Error(e)) => { app.last_error = Some(e); processed += 1; }
-                    Ok(AppEvent::Tick) => { processed += 1; }
-                    Err(mpsc::TryRecvError::Empty) => break,
-                    Err(mpsc::TryRecvError::Disconnected) => break,
+                    Ok(event) => app.handle_event(event),
+                    Err(mpsc::TryRecvError::Empty | mpsc::TryRecvError::Disconnected) => break,
                 }
+                processed += 1;
             }
+            // Keep buffering targets that are not on screen
+            app.drain_background();
             // Periodic discovery whilst in selection menu
             app.maybe_refresh_discovered();
-            if processed == MAX_EVENTS_PER_TICK {
-                // Inform user that we're throttling to keep UI responsive
-                app.last_error = Some("High log throughput: throttling display to keep UI responsive".to_string());
-            }
+            app.maybe_reload_config();
+            app.maybe_check_disk();
         }
     }
 
+    // Remember window options for next time; best effort
+    app.save_state();
+    app.stop_all_tails();
+
     // Teardown
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+    execute!(terminal.backend_mut(), DisableMouseCapture)?;
+    if alt_screen {
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    } else {
+        // leave the last frame in place and continue the shell below it
+        let rows = terminal.size()?.height;
+        terminal.set_cursor(0, rows.saturating_sub(1))?;
+        terminal.backend_mut().write_all(b"\n")?;
+    }
     terminal.show_cursor()?;
     Ok(())
 }
@@ -151,28 +897,130 @@ fn main() -> Result<()> {
 struct App {
     mode: Mode,
     cfg: Config,
+    config_paths: Vec<PathBuf>,            // files the config was merged from, watched for edits
+    config_mtimes: Vec<Option<std::time::SystemTime>>,
+    last_config_check: Instant,
+    state_path: PathBuf,                   // where UiState is persisted
+    color_rules: Arc<Vec<ColorRule>>,
+    highlights: Vec<Highlight>,            // config keywords painted at render time
+    show_highlights: bool,
+    filter_presets: Vec<FilterPreset>,     // F1-F12 filter sets from the config
+    theme: Theme,                          // config theme; Ctrl+T switches its base
+    no_color: bool,                        // NO_COLOR or TERM=dumb: monochrome theme, colors stripped after drawing
+    progress_parsers: Vec<ProgressParser>, // "progress" entries from the config
+    ansi_colors: bool,                     // off: ANSI codes from the log are stripped without painting
+    severity_bands: bool,                  // tint the whole row of errors and warnings
+    category_palette: Vec<Color>,
+    category_colors: HashMap<String, Color>, // color_for_category results, filled as categories show up
+    collapse_duplicates: bool,             // fold runs of identical lines into one row with a counter
+    hidden_categories: HashSet<String>,    // config denylist, applied under every other filter
+    show_hidden: bool,                     // Shift+F: ignore the denylist for now
+    group_multiline: bool,                 // Z: stack traces and JSON blobs inherit their header line's category
+    mouse_enabled: bool,                   // off: the terminal keeps native selection
+    max_lines: usize,
     // selection
     selected: usize,
+    checked: Vec<usize>,                   // targets marked with Space for a merged view
+    current_root: Option<PathBuf>,         // directory of the (first) target on screen
+    watching: Vec<PathBuf>,                // log files on screen, in full; the footer shows them shortened
+    current_key: Option<String>,           // key of the target on screen, for its saved filters
+    target_filters: BTreeMap<String, TargetFilters>,
+    pending_editor: Option<(PathBuf, usize)>, // $EDITOR run requested; main suspends the UI for it
+    recorder: Option<std::io::LineWriter<File>>, // --record: every received line is appended here
+    selection_moved_at: Option<Instant>,   // last manual move in the menu; discovery won't steal the cursor
+    select_query: Option<String>,          // menu filter typed after '/'; `selected` stays on a matching entry
     // view
     current_name: Option<String>,
-    current_is_build: bool,
+    current_accent: Color,                 // header color of the open target
     lines: Vec<LogLine>,
+    lines_dropped: usize,      // lines evicted from the front so far; with the index, a stable line number
+    lines_epoch: u64,          // bumped whenever `lines` is cleared, swapped or rewritten in place
+    filter_cache: RefCell<FilterCache>,
+    row_cache: Option<(u64, Vec<Line<'static>>, Vec<RowRef>)>, // styled rows of the last frame, their lines and what they were built from
+    config_generation: u64,    // bumped on config reload (highlights, palette and formats feed the rows)
     scroll_from_bottom: usize, // 0 = bottom, grows when user scrolls up
     last_error: Option<String>,
     // rendering state / options
     show_timestamp: bool,                  // toggleable, default off
+    show_elapsed: bool,                    // timestamp column shows +mm:ss.mmm since session_start
+    session_start: Option<chrono::NaiveDateTime>, // first parsed timestamp of the session
+    show_line_numbers: bool,               // gutter with each line's number since the log was opened
+    show_header: bool,                     // Shift+H cycles these two off and on for more log rows
+    show_footer: bool,
+    toast: Option<(String, Instant)>,      // footer hidden: last_error overlaid on the body for a moment
+    sticky_header: bool,                   // banner row with the dominant visible category
     wrap_lines: bool,                      // default: true (word wrap enabled)
-    active_category_filter: Option<String>,
+    show_raw: bool,                        // render `text` as read, without the parsed columns
+    h_scroll: usize,                       // chars of each message skipped when not wrapping
+    active_categories: HashSet<String>,    // empty = show all categories
+    active_regex_filter: Option<regex::Regex>,
+    active_time_filter: Option<(TimePoint, Option<TimePoint>)>, // lines without a parsed timestamp are hidden
+    active_source_filter: Option<String>,
+    min_verbosity: Option<Verbosity>,      // hide lines less severe than this
     last_body_area: Rect,                  // for mouse hit testing
+    last_row_lines: Vec<RowRef>,           // the line behind each body row on screen
+    last_status_area: Rect,                // footer message; a click on "Watching: …" copies the paths
+    last_minimap_area: Rect,
     show_help: bool,                       // help popup visibility
+    stats: Option<Stats>,                  // stats popup, Some while visible
+    stats_scroll: usize,
+    detail: Option<LogLine>,               // Enter: full-message popup, a copy so eviction can't pull it away
+    detail_scroll: u16,
+    category_picker: Option<CategoryPicker>,
+    split: Option<SplitPane>,              // split view: the unfocused pane
+    crash_alert: bool,                     // a crash marker arrived while following
+    last_bell: Option<Instant>,            // `bell` rate limit; also when a visual one started
+    error_stop: Option<usize>,             // rows still followed after a stop_following_on line
+    stopped_on_error: bool,                // following stopped by stop_following_on (footer says so)
+    confirm_quit: bool,                    // quit pressed mid-cook; waiting for y/N
+    pending_motion: Option<PendingMotion>, // vim count / first `g` typed so far
+    paused: bool,                          // hold incoming lines instead of displaying them
+    held: Vec<LogLine>,                    // lines received while paused
+    queued: Arc<AtomicUsize>,              // lines sent by the tails on screen and not handled yet
+    // search
+    input: Option<InputLine>,              // footer input line while typing
+    search_query: Option<SearchQuery>,
+    search_ignore_case: bool,              // prompt toggles, kept for the next search
+    search_whole_word: bool,
+    search_all_categories: bool,           // n/N and Enter look past the category filter and switch it to the match
+    search_history: Vec<String>,           // submitted '/' queries, oldest first
+    filter_history: Vec<String>,           // submitted 'r' regex filters
+    search_current: Option<usize>,         // index into `lines` of the focused match
+    // clipboard
+    selected_line: Option<usize>,          // index into `lines` picked with a click
+    last_click: Option<(Instant, u16, u16)>, // time, column and row of the previous line click
+    clipboard: Option<arboard::Clipboard>, // opened lazily; kept alive so X11 selections persist
     // COOK progress state
     cook_active: bool,
     cook_cooked: u64,
     cook_remain: u64,
     cook_total: u64,
-    // tail thread channels
-    rx: mpsc::Receiver<AppEvent>,
-    tx_cmd: mpsc::Sender<Cmd>,
+    cook_samples: VecDeque<(Instant, u64)>, // (arrival, cooked) over the last minute, for the ETA
+    cook_source: Option<String>,           // source of the latest cook line in a merged view
+    disk_free: Option<u64>,                // free bytes on the logs' volume, sampled while cooking
+    last_disk_check: Option<Instant>,
+    recent_errors: VecDeque<Instant>,      // arrival times inside the error-spike window
+    recent_lines: VecDeque<Instant>,       // arrival times over the last second, for the rate
+    // Shader compile / Live Coding state
+    shaders_outstanding: u64,              // 0 = no shader compile in progress
+    shaders_peak: u64,                     // largest outstanding count of the current burst
+    live_coding: Option<LiveCoding>,
+    progress: Option<Progress>,            // configured progress parsers
+    build_info: BuildInfo,                 // engine version etc. of a single-log view, shown in the header
+    last_seen_index: Option<usize>,        // first line that arrived after the user scrolled up
+    seen_marker_clear_at: Option<Instant>, // back at the bottom: when to drop the marker
+    tail_from_start: bool,                 // new tails read the whole file first
+    path_style: PathStyle,                 // select list path display
+    backlog_pending: usize,                // tails still streaming their initial backlog
+    tail_status: HashMap<PathBuf, Option<u64>>, // last reported size per tailed file (None: missing)
+    // tail thread channels (one command sender per tailed log)
+    rx: mpsc::Receiver<TailEvent>,
+    tx_cmds: Vec<mpsc::Sender<Cmd>>,
+    child_threads: Vec<thread::JoinHandle<()>>, // tails owning a child process; joined on quit so none is orphaned
+    tx_events: Option<mpsc::Sender<TailEvent>>, // feeds `rx`, for tails added to the open view
+    sibling_scans: Vec<SiblingScan>,       // folders of `all_logs` projects on screen
+    // previously viewed targets, keyed by display name
+    background: HashMap<String, TargetState>,
     // discovery refresh
     last_discovery_check: Instant,
 }
@@ -181,32 +1029,146 @@ struct App {
 enum Action { Continue, Quit }
 
 impl App {
-    fn new(cfg: Config) -> Self {
-        let (tx_ev, rx) = mpsc::channel::<AppEvent>();
-        let (tx_cmd, rx_cmd) = mpsc::channel::<Cmd>();
-        // idle tail thread doing nothing until a project is started
-        spawn_idle_tail(tx_ev.clone(), rx_cmd);
+    fn new(cfg: Config, color_rules: Vec<ColorRule>, highlights: Vec<Highlight>, presets: Vec<FilterPreset>, progress: Vec<ProgressParser>, config_paths: Vec<PathBuf>) -> Self {
+        let state_path = state_path_for(config_paths.last().map(PathBuf::as_path));
+        let config_mtimes = config_mtimes(&config_paths);
+        // say where targets come from when several files contribute
+        let config_status = (config_paths.len() > 1).then(|| format!("Config merged from {}", join_paths(&config_paths)));
+        let (rx, tx_cmds) = idle_channels();
+        let max_lines = cfg.max_lines.unwrap_or(DEFAULT_MAX_LINES).max(1);
+        let ansi_colors = cfg.ansi_colors.unwrap_or(true);
+        let severity_bands = cfg.severity_bands.unwrap_or(false);
+        let category_palette = cfg.category_palette();
+        let hidden_categories = cfg.hidden_categories.iter().cloned().collect();
+        let group_multiline = cfg.group_multiline.unwrap_or(true);
+        let no_color = colors_disabled();
+        // checked while parsing the config
+        let theme = Theme::new(&cfg.theme, no_color.then_some("monochrome")).unwrap_or_default();
+        let current_accent = theme.header_project;
+        // missing or malformed state silently means defaults
+        let state: UiState = fs::read(&state_path).ok()
+            .and_then(|b| serde_json::from_slice(&b).ok())
+            .unwrap_or_default();
+        let targets = cfg.target_count();
         Self {
             mode: Mode::Select,
             cfg,
-            selected: 0,
+            config_paths,
+            config_mtimes,
+            last_config_check: Instant::now(),
+            state_path,
+            color_rules: Arc::new(color_rules),
+            highlights,
+            show_highlights: true,
+            filter_presets: presets,
+            theme,
+            no_color,
+            progress_parsers: progress,
+            ansi_colors,
+            severity_bands,
+            category_palette,
+            category_colors: HashMap::new(),
+            collapse_duplicates: false,
+            hidden_categories,
+            show_hidden: false,
+            group_multiline,
+            mouse_enabled: true,
+            max_lines,
+            selected: state.selected.min(targets.saturating_sub(1)),
+            checked: Vec::new(),
+            current_root: None,
+            watching: Vec::new(),
+            current_key: None,
+            target_filters: state.targets,
+            pending_editor: None,
+            recorder: None,
+            selection_moved_at: None,
+            select_query: None,
             current_name: None,
-            current_is_build: false,
+            current_accent,
             lines: Vec::new(),
+            lines_dropped: 0,
+            lines_epoch: 0,
+            filter_cache: RefCell::default(),
+            row_cache: None,
+            config_generation: 0,
             scroll_from_bottom: 0,
-            last_error: None,
-            show_timestamp: false,
-            wrap_lines: true,
-            active_category_filter: None,
+            last_error: config_status,
+            show_timestamp: state.show_timestamp,
+            show_elapsed: false,
+            show_raw: false,
+            session_start: None,
+            show_line_numbers: state.show_line_numbers,
+            show_header: state.show_header,
+            show_footer: state.show_footer,
+            toast: None,
+            sticky_header: false,
+            wrap_lines: state.wrap_lines,
+            h_scroll: 0,
+            active_categories: HashSet::new(),
+            active_regex_filter: None,
+            active_time_filter: None,
+            active_source_filter: None,
+            min_verbosity: None,
             last_body_area: Rect::new(0, 0, 0, 0),
+            last_row_lines: Vec::new(),
+            last_status_area: Rect::new(0, 0, 0, 0),
+            last_minimap_area: Rect::new(0, 0, 0, 0),
             show_help: false,
+            stats: None,
+            stats_scroll: 0,
+            detail: None,
+            detail_scroll: 0,
+            category_picker: None,
+            split: None,
+            crash_alert: false,
+            last_bell: None,
+            error_stop: None,
+            stopped_on_error: false,
+            confirm_quit: false,
+            pending_motion: None,
+            last_click: None,
+            paused: false,
+            held: Vec::new(),
+            queued: Arc::default(),
+            input: None,
+            search_query: None,
+            search_ignore_case: false,
+            search_whole_word: false,
+            search_all_categories: false,
+            search_history: state.search_history,
+            filter_history: state.filter_history,
+            search_current: None,
+            selected_line: None,
+            clipboard: None,
             // cook progress initial state
             cook_active: false,
             cook_cooked: 0,
             cook_remain: 0,
             cook_total: 0,
+            cook_samples: VecDeque::new(),
+            cook_source: None,
+            disk_free: None,
+            last_disk_check: None,
+            recent_errors: VecDeque::new(),
+            recent_lines: VecDeque::new(),
+            shaders_outstanding: 0,
+            shaders_peak: 0,
+            live_coding: None,
+            progress: None,
+            build_info: BuildInfo::default(),
+            last_seen_index: None,
+            seen_marker_clear_at: None,
+            tail_from_start: false,
+            path_style: state.path_style,
+            backlog_pending: 0,
+            tail_status: HashMap::new(),
             rx,
-            tx_cmd,
+            tx_cmds,
+            child_threads: Vec::new(),
+            tx_events: None,
+            sibling_scans: Vec::new(),
+            background: HashMap::new(),
             last_discovery_check: Instant::now() - Duration::from_secs(10),
         }
     }
@@ -217,149 +1179,327 @@ impl App {
         match self.mode {
             Mode::Select => {
                 let mut items: Vec<ListItem> = Vec::new();
+                let matches = self.select_matches();
+                let no_match: &[usize] = &[];
+                let matched = |i: usize| matches[i].as_ref().map_or(no_match, |(_, pos)| pos.as_slice());
+                let multi = !self.checked.is_empty();
+                let mark = |i: usize| -> &'static str {
+                    if !multi { "" } else if self.checked.contains(&i) { " [x]" } else { " [ ]" }
+                };
+                // 1-9 shortcut shown in front of the first nine entries (digits are typed into the filter instead)
+                let typing = self.select_query.is_some();
+                let number = |i: usize| -> Span<'static> {
+                    let label = if i < 9 && !typing { format!("{} ", i + 1) } else { "  ".to_string() };
+                    Span::styled(label, Style::default().fg(Color::DarkGray))
+                };
                 // Projects
-                for p in &self.cfg.projects {
-                    let mut title = if p.name.is_empty() { p.key.clone() } else { p.name.clone() };
-                    if p.discovered { title.push_str("  [discovered]"); }
-                    let path = p.uproject.display().to_string();
-                    items.push(ListItem::new(Line::from(vec![
+                for (i, p) in self.cfg.projects.iter().enumerate() {
+                    let path = shorten_path(&p.uproject, self.path_style);
+                    let mut spans = vec![
+                        number(i),
+                        Span::styled(mark(i), Style::default().fg(self.theme.accent)),
                         Span::raw(" [Project] "),
-                        Span::styled(title, Style::default().fg(Color::Cyan)),
+                    ];
+                    let style = Style::default().fg(accent_color(p.color.as_deref(), self.theme.header_project));
+                    spans.extend(fuzzy_spans(&p.name_or_key(), matched(i), style));
+                    spans.extend([
+                        Span::raw(if p.discovered { "  [discovered]" } else { "" }),
+                        Span::styled(if p.running { "  ● running" } else { "" }, Style::default().fg(self.theme.accent)),
                         Span::raw("\n   "),
                         Span::styled(path, Style::default().fg(Color::DarkGray)),
-                    ])));
+                    ]);
+                    items.push(ListItem::new(Line::from(spans)));
                 }
                 // Builds
-                for b in &self.cfg.builds {
-                    let title = if b.name.is_empty() { b.key.clone() } else { b.name.clone() };
-                    let path = b.exe.display().to_string();
-                    items.push(ListItem::new(Line::from(vec![
+                let pcount = self.cfg.projects.len();
+                for (i, b) in self.cfg.builds.iter().enumerate() {
+                    let path = shorten_path(&b.exe, self.path_style);
+                    let mut spans = vec![
+                        number(pcount + i),
+                        Span::styled(mark(pcount + i), Style::default().fg(self.theme.accent)),
                         Span::raw(" [Build]   "),
-                        Span::styled(title, Style::default().fg(Color::Magenta)),
+                    ];
+                    let style = Style::default().fg(accent_color(b.color.as_deref(), self.theme.header_build));
+                    spans.extend(fuzzy_spans(&b.name_or_key(), matched(pcount + i), style));
+                    spans.extend([
                         Span::raw("\n   "),
                         Span::styled(path, Style::default().fg(Color::DarkGray)),
-                    ])));
+                    ]);
+                    items.push(ListItem::new(Line::from(spans)));
+                }
+                // Remotes
+                let bcount = pcount + self.cfg.builds.len();
+                for (i, r) in self.cfg.remotes.iter().enumerate() {
+                    let mut spans = vec![
+                        number(bcount + i),
+                        Span::styled(mark(bcount + i), Style::default().fg(self.theme.accent)),
+                        Span::raw(" [Remote]  "),
+                    ];
+                    let style = Style::default().fg(accent_color(r.color.as_deref(), self.theme.header_remote));
+                    spans.extend(fuzzy_spans(&r.name_or_key(), matched(bcount + i), style));
+                    spans.extend([
+                        Span::raw("\n   "),
+                        Span::styled(r.command.clone(), Style::default().fg(Color::DarkGray)),
+                    ]);
+                    items.push(ListItem::new(Line::from(spans)));
                 }
+                // the filter hides entries that don't match; the highlight follows `selected` among the rest
+                let shown: Vec<usize> = (0..items.len()).filter(|&i| matches[i].is_some()).collect();
+                let items: Vec<ListItem> = items.into_iter().enumerate()
+                    .filter_map(|(i, item)| matches[i].is_some().then_some(item))
+                    .collect();
+                let highlighted = shown.iter().position(|&i| i == self.selected);
 
+                let title = format!(
+                    "Select target (Enter) — /: filter — Space: mark several for a merged view — B: start at {} — P: paths — Quit: Q",
+                    if self.tail_from_start { "beginning of log" } else { "end of log" }
+                );
                 let list = List::new(items)
-                    .block(Block::default().title("Select target (Enter) — Quit: Q").borders(Borders::ALL))
+                    .block(Block::default().title(title).borders(Borders::ALL))
                     .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
-                f.render_stateful_widget(list, size, &mut ratatui::widgets::ListState::default().with_selected(Some(self.selected)));
+                // status line under the list (the filter being typed, config reloads, discovery notices)
+                let status_text = match &self.select_query {
+                    Some(q) if shown.is_empty() => Some(format!("/{}  (no match, Esc clears)", q)),
+                    Some(q) => Some(format!("/{}  ({} of {}, Esc clears)", q, shown.len(), matches.len())),
+                    None => self.last_error.clone(),
+                };
+                let (list_area, status_area) = if status_text.is_some() {
+                    let parts = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+                        .split(size);
+                    (parts[0], Some(parts[1]))
+                } else {
+                    (size, None)
+                };
+                if self.cfg.target_count() == 0 {
+                    f.render_widget(self.empty_config_guide(), list_area);
+                } else {
+                    f.render_stateful_widget(list, list_area, &mut ratatui::widgets::ListState::default().with_selected(highlighted));
+                }
+                if let Some(area) = status_area {
+                    let status = Paragraph::new(status_text.unwrap_or_default())
+                        .style(Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC));
+                    f.render_widget(status, area);
+                }
             }
             Mode::View => {
+                // a hidden footer still comes back while typing into it
+                let footer_shown = self.show_footer || self.input.is_some();
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
-                    .constraints([Constraint::Length(1), Constraint::Min(1), Constraint::Length(1)].as_ref())
+                    .constraints([Constraint::Length(self.show_header as u16), Constraint::Min(1), Constraint::Length(footer_shown as u16)].as_ref())
                     .split(size);
 
                 // Header: left shows only current target name; right shows filter/progress
+                let preset = self.active_preset().map(|p| format!("Preset: {} | ", p.name)).unwrap_or_default();
+                let build = self.build_info.summary().map(|b| format!("{} | ", b)).unwrap_or_default();
                 let left_title = if let Some(name) = &self.current_name {
-                    format!(" {} | H -> Help", name)
+                    format!(" {} | {}{}H -> Help", name, build, preset)
+                } else {
+                    format!(" {}H -> Help ", preset)
+                };
+                let mut filters: Vec<String> = Vec::new();
+                if let Some(src) = &self.active_source_filter { filters.push(format!("[{}]", src)); }
+                if !self.active_categories.is_empty() {
+                    let mut cats: Vec<&str> = self.active_categories.iter().map(|c| c.as_str()).collect();
+                    cats.sort_unstable();
+                    filters.push(cats.join(", "));
+                }
+                if let Some(re) = &self.active_regex_filter { filters.push(format!("/{}/", re.as_str())); }
+                if let Some((from, to)) = &self.active_time_filter {
+                    filters.push(format!("@{}..{}", from, to.map(|t| t.to_string()).unwrap_or_default()));
+                }
+                if let Some(v) = self.min_verbosity { filters.push(format!(">= {}", v.as_str())); }
+                let right_title = if filters.is_empty() {
+                    String::new()
                 } else {
-                    " H -> Help ".to_string()
+                    format!("Filter: {} (clear: F)", filters.join(" + "))
                 };
-                let right_title = if let Some(cat) = &self.active_category_filter {
-                    format!("Filter: {} (clear: F)", cat)
-                } else { String::new() };
                 let hchunks = Layout::default()
                     .direction(Direction::Horizontal)
                     .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
                     .split(chunks[0]);
-                let header_color = if self.current_is_build { Color::Magenta } else { Color::Cyan };
-                let header_left = Paragraph::new(left_title).style(Style::default().fg(header_color));
+                let header_left = Paragraph::new(left_title).style(Style::default().fg(self.current_accent));
                 f.render_widget(header_left, hchunks[0]);
 
-                // Right header: show COOK progress if active, otherwise filter info
+                // Right header: COOK, shader/Live Coding and configured progress gauges side by side if active,
+                // otherwise filter info
+                let mut gauges: Vec<Gauge> = Vec::new();
+                let compile_active = self.shaders_outstanding > 0 || matches!(self.live_coding, Some(LiveCoding::Compiling));
+                // a failed compile keeps its gauge until the next one starts
+                if compile_active || self.live_coding == Some(LiveCoding::Failed) {
+                    let (label, ratio, color) = match self.live_coding {
+                        Some(LiveCoding::Failed) => ("LIVE CODING FAILED".to_string(), 1.0, self.theme.error_color()),
+                        Some(LiveCoding::Compiling) if self.shaders_outstanding == 0 => ("LIVE CODING compiling".to_string(), 0.0, Color::Blue),
+                        _ => {
+                            let done = self.shaders_peak.saturating_sub(self.shaders_outstanding);
+                            let ratio = if self.shaders_peak > 0 { done as f64 / self.shaders_peak as f64 } else { 0.0 };
+                            (format!("SHADERS {} outstanding", self.shaders_outstanding), ratio, Color::Blue)
+                        }
+                    };
+                    gauges.push(Gauge::default()
+                        .gauge_style(Style::default().fg(color))
+                        .label(Span::raw(label))
+                        .ratio(ratio));
+                }
                 if self.cook_active {
                     let total = if self.cook_total > 0 { self.cook_total } else { self.cook_cooked + self.cook_remain };
                     let ratio = if total > 0 { (self.cook_cooked as f64 / total as f64).clamp(0.0, 1.0) } else { 0.0 };
+                    let cook = match &self.cook_source {
+                        Some(src) => format!("COOK [{}]", src),
+                        None => "COOK".to_string(),
+                    };
                     let label = if total > 0 {
-                        format!("COOK {:>3}%  ({} / {} | remain {})", (ratio * 100.0).round() as u64, self.cook_cooked, total, self.cook_remain)
+                        let eta = self.cook_eta().map(|left| format!("  {} remaining", format_eta(left))).unwrap_or_default();
+                        format!("{} {:>3}%  ({} / {} | remain {}){}", cook, (ratio * 100.0).round() as u64, self.cook_cooked, total, self.cook_remain, eta)
                     } else {
-                        "COOK in progress".to_string()
+                        format!("{} in progress", cook)
                     };
-                    let gauge = Gauge::default()
-                        .gauge_style(Style::default().fg(Color::Green))
+                    let (label, color) = match self.low_disk() {
+                        // first, so a narrow header still shows it
+                        Some(free) => (format!("LOW DISK {} free | {}", format_bytes(free as usize), label), self.theme.error_color()),
+                        None => (label, self.theme.accent),
+                    };
+                    // the cook comes first, left of the others
+                    gauges.insert(0, Gauge::default()
+                        .gauge_style(Style::default().fg(color))
                         .label(Span::raw(label))
-                        .ratio(ratio);
-                    f.render_widget(gauge, hchunks[1]);
-                } else {
-                    let header_right = Paragraph::new(right_title).style(Style::default().fg(Color::Yellow)).alignment(Alignment::Right);
+                        .ratio(ratio));
+                }
+                if let Some(p) = self.progress.as_ref().filter(|p| p.current < p.total && p.updated.elapsed() < PROGRESS_IDLE) {
+                    let parser = &self.progress_parsers[p.parser];
+                    let ratio = p.current as f64 / p.total as f64;
+                    gauges.push(Gauge::default()
+                        .gauge_style(Style::default().fg(parser.color))
+                        .label(Span::raw(format!("{} {:>3}%  ({} / {})", parser.label, (ratio * 100.0).round() as u64, p.current, p.total)))
+                        .ratio(ratio));
+                }
+                if gauges.is_empty() {
+                    let header_right = Paragraph::new(right_title).style(Style::default().fg(self.theme.warning_color())).alignment(Alignment::Right);
                     f.render_widget(header_right, hchunks[1]);
+                } else {
+                    let n = gauges.len() as u32;
+                    let parts = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints(vec![Constraint::Ratio(1, n); gauges.len()])
+                        .split(hchunks[1]);
+                    for (gauge, area) in gauges.into_iter().zip(parts.iter()) {
+                        f.render_widget(gauge, *area);
+                    }
                 }
 
                 // Prepare filtered lines
-                let filtered: Vec<&LogLine> = if let Some(cat) = &self.active_category_filter {
-                    self.lines.iter().filter(|l| l.category.as_deref() == Some(cat.as_str())).collect()
+                let filtered = self.filtered_indices();
+                let matches = self.search_matches(&filtered);
+
+                // Log body, or two panes side by side in split view
+                if let Some(split) = &self.split {
+                    let panes = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                        .split(chunks[1]);
+                    let (mine, other) = if split.focus_second { (panes[1], panes[0]) } else { (panes[0], panes[1]) };
+                    self.draw_body(f, mine, &filtered, Some(true));
+                    self.with_other_pane(|app| {
+                        let filtered = app.filtered_indices();
+                        app.draw_body(f, other, &filtered, Some(false));
+                    });
                 } else {
-                    self.lines.iter().collect()
-                };
+                    self.draw_body(f, chunks[1], &filtered, None);
+                }
 
-                // Log body – compute visible slice based on scroll_from_bottom
-                let h = chunks[1].height as usize;
-                let total = filtered.len();
-                let end = total.saturating_sub(self.scroll_from_bottom);
-                let start = end.saturating_sub(h);
-                let slice = &filtered[start..end];
-
-                // remember body area for mouse clicks
-                self.last_body_area = chunks[1];
-
-                let mut lines_vec: Vec<Line> = Vec::with_capacity(slice.len());
-                // content width inside the bordered block
-                let content_width = chunks[1].width.saturating_sub(2) as usize;
-                for l in slice.iter() {
-                    let mut spans: Vec<Span> = Vec::new();
-                    let mut prefix_len = 0usize;
-                    if self.show_timestamp {
-                        if let Some(ts) = &l.ts {
-                            let ts_part = format!("[{}] ", ts);
-                            prefix_len += ts_part.chars().count();
-                            spans.push(Span::styled(ts_part, Style::default().fg(Color::DarkGray)));
-                        }
+                // Footer: input line while typing, otherwise status – not red, italic preferred
+                if let Some(input) = &self.input {
+                    let label = match input.prompt {
+                        Prompt::Search => "/",
+                        Prompt::Regex => "Regex filter: ",
+                        Prompt::TimeRange => "Time range (10:30..10:45, end optional): ",
+                        Prompt::JumpTime => "Jump to time: ",
+                    };
+                    let text = format!("{}{}", label, input.buffer);
+                    let cursor_x = chunks[2].x + text.chars().count() as u16;
+                    f.render_widget(Paragraph::new(text).style(Style::default().fg(self.theme.warning_color())), chunks[2]);
+                    if input.prompt == Prompt::Search {
+                        let on_off = |b: bool| if b { "on" } else { "off" };
+                        let hint = format!(
+                            "Ctrl+C ignore case: {}  Ctrl+W whole word: {}  Ctrl+A all categories: {} ",
+                            on_off(self.search_ignore_case), on_off(self.search_whole_word), on_off(self.search_all_categories)
+                        );
+                        f.render_widget(Paragraph::new(hint).style(Style::default().fg(Color::DarkGray)).alignment(Alignment::Right), chunks[2]);
                     }
-                    if let Some(cat) = &l.category {
-                        let cat_part = format!("{}:", cat);
-                        prefix_len += cat_part.chars().count();
-                        spans.push(Span::styled(cat_part, Style::default().add_modifier(Modifier::UNDERLINED).fg(Color::Cyan)));
-                        prefix_len += 1; // space after category
-                        spans.push(Span::raw(" "));
-                    }
-                    // message (or original text if no parsed parts)
-                    let msg = if l.category.is_some() || l.ts.is_some() { l.message.as_str() } else { l.text.as_str() };
-                    if self.wrap_lines {
-                        spans.push(Span::styled(msg, Style::default().fg(l.color)));
+                    f.set_cursor(cursor_x.min(chunks[2].right().saturating_sub(1)), chunks[2].y);
+                } else if footer_shown {
+                    let mut status: Vec<String> = Vec::new();
+                    status.push(format!("{}/{} lines ~{}", self.lines.len(), self.max_lines, format_bytes(self.approx_bytes())));
+                    status.push(if self.scroll_from_bottom == 0 {
+                        "FOLLOWING".to_string()
+                    } else if self.stopped_on_error {
+                        "PAUSED ON ERROR (resume: End)".to_string()
                     } else {
-                        let mut remaining = content_width.saturating_sub(prefix_len);
-                        let msg_len = msg.chars().count();
-                        let truncated = if msg_len > remaining {
-                            // ensure room for ellipsis
-                            if remaining >= 3 { remaining -= 3; }
-                            let taken: String = msg.chars().take(remaining.max(0)).collect();
-                            format!("{}...", taken)
-                        } else {
-                            msg.to_string()
-                        };
-                        spans.push(Span::styled(truncated, Style::default().fg(l.color)));
+                        format!("SCROLLED (+{})", self.scroll_from_bottom)
+                    });
+                    status.push(scroll_position(filtered.len(), self.scroll_from_bottom, self.body_rows()));
+                    if let Some(p) = self.pending_motion {
+                        let count = if p.count > 0 { p.count.to_string() } else { String::new() };
+                        status.push(format!("{}{}", count, if p.g { "g" } else { "" }));
                     }
-                    lines_vec.push(Line::from(spans));
-                }
-
-                let mut body = Paragraph::new(lines_vec)
-                    .block(Block::default().borders(Borders::ALL).title("Logs"))
-                    .scroll((0, 0));
-                if self.wrap_lines {
-                    body = body.wrap(ratatui::widgets::Wrap { trim: false });
-                }
-                f.render_widget(body, chunks[1]);
-
-                // Footer status – not red, italic preferred
-                let footer = Paragraph::new(
-                    self.last_error.clone().unwrap_or_default()
-                ).style(Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC));
-                f.render_widget(footer, chunks[2]);
-
+                    if !self.wrap_lines && self.h_scroll > 0 {
+                        status.push(format!("→ +{}", self.h_scroll));
+                    }
+                    if !self.tail_status.is_empty() {
+                        let missing = self.tail_status.values().filter(|s| s.is_none()).count();
+                        status.push(match missing {
+                            0 => format!("tailing ({})", format_bytes(self.tail_status.values().flatten().sum::<u64>() as usize)),
+                            1 if self.tail_status.len() == 1 => "waiting for log file…".to_string(),
+                            n => format!("waiting for {} of {} log files…", n, self.tail_status.len()),
+                        });
+                    }
+                    status.push(self.cfg.poll_interval().label());
+                    if let Some(s) = self.last_seen_index.filter(|&s| s < self.lines.len()) {
+                        status.push(format!("{} new", filtered.iter().filter(|&&i| i >= s).count()));
+                    }
+                    if self.backlog_pending > 0 {
+                        status.push("Loading backlog…".to_string());
+                    }
+                    let behind = self.queued.load(Ordering::Relaxed);
+                    if behind > MAX_EVENTS_PER_TICK {
+                        status.push(format!("behind by ~{} lines", behind));
+                    }
+                    if self.paused {
+                        status.push(format!("PAUSED (+{} held)", self.held.len()));
+                    }
+                    if let Some(q) = &self.search_query {
+                        let rank = self.search_current
+                            .and_then(|cur| matches.iter().position(|&p| filtered[p] == cur))
+                            .map(|p| p + 1)
+                            .unwrap_or(0);
+                        status.push(format!("/{} {} {}/{}", q.text, q.flags(), rank, matches.len()));
+                    }
+                    status.push(format!("{} lines/s", self.lines_per_sec()));
+                    status.push(chrono::Local::now().format("%H:%M:%S").to_string());
+                    let status = status.join("  ");
+                    let fchunks = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Min(1), Constraint::Length(status.chars().count() as u16 + 1)].as_ref())
+                        .split(chunks[2]);
+                    let footer = if self.crash_alert {
+                        Paragraph::new("CRASH DETECTED — press X to jump")
+                            .style(self.theme.alert().add_modifier(Modifier::SLOW_BLINK))
+                    } else {
+                        Paragraph::new(
+                            self.last_error.clone().unwrap_or_default()
+                        ).style(Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC))
+                    };
+                    f.render_widget(footer, fchunks[0]);
+                    self.last_status_area = fchunks[0];
+                    let footer_right = Paragraph::new(status).style(Style::default().fg(self.theme.warning_color())).alignment(Alignment::Right);
+                    f.render_widget(footer_right, fchunks[1]);
+                } else {
+                    self.draw_toast(f, chunks[1]);
+                }
+
                 // Help popup overlay
                 if self.show_help {
                     // Centered area ~80% of screen
@@ -367,24 +1507,83 @@ impl App {
                     let h = (size.height as f32 * 0.8) as u16;
                     let area = Rect::new((size.width - w) / 2, (size.height - h) / 2, w, h);
 
-                    let help_text = [
+                    let mut help_lines = vec![
                         "Commands:",
                         "",
                         " H              Show/Hide this help",
+                        " Shift+H        Cycle header/footer rows (hide them on small terminals)",
+                        " Ctrl+T         Switch theme (default / colorblind)",
+                        " I              Show/Hide statistics by category (E / Shift+E in it: save as JSON / CSV)",
                         " Q / Esc        Quit the app",
                         " S              Back to project/build selection",
+                        " 1-9            Switch to the Nth target of the selection list (Esc cancels)",
                         " C              Clear output and restart tail",
-                        " F              Clear category filter",
+                        " Y              Copy selected line (or visible lines) to clipboard",
+                        " Enter          Show the selected (or bottom) line in full, with its fields broken out",
+                        " o              Open the file(line) referenced by the selected line",
+                        " X              Jump to the first crash/assert marker",
+                        " Shift+O        Open the log's folder in the file manager (click Watching: to copy its path)",
+                        " P              Pause/resume the live view (lines are held, not dropped)",
+                        " Shift+C        Pick categories to filter with the keyboard",
+                        " F              Clear source, category and regex filters",
+                        " Ctrl+F         Reset filters and forget this target's saved ones",
+                        " Shift+F        Show/hide the config's hidden_categories",
+                        " F1-F12         Apply/remove a filter preset from the config",
+                        " R              Filter by regex (category or full line)",
+                        " Shift+J        Filter by time range, e.g. 10:30..10:45 (empty clears)",
+                        " J              Jump to the first line at or after a time",
+                        " V / Shift+V    Raise/lower minimum verbosity (e.g. only Warning and above)",
+                        " Shift+R        Show lines exactly as read (no timestamp/category reformatting)",
                         " T              Toggle timestamp",
+                        " Shift+T        Toggle elapsed time (+mm:ss.mmm) instead of wall clock",
+                        " L / #          Toggle line numbers",
+                        " K              Toggle sticky category header",
+                        " U              Toggle keyword highlights from the config",
+                        " A              Toggle ANSI colors embedded in the log (off: stripped)",
+                        " B              Toggle red/yellow row backgrounds for errors and warnings",
+                        " D              Collapse repeated lines into one row with an (xN) counter",
+                        " Z              Group lines without timestamp/category (stack traces, JSON) under the line above",
                         " W              Toggle word wrap",
+                        " |              Split view: two panes with their own categories and scroll",
+                        " Tab            Switch the focused pane in split view",
+                        " E              Export current view to a file (Shift+E: keep colors)",
+                        " M              Toggle mouse capture (off: select text with the terminal)",
+                        "",
+                        " Search:",
+                        "  /             Type a query, Enter jumps to next match below",
+                        "  n / N         Next/previous match",
+                        "  Ctrl+A        (in the prompt) Search outside the category filter, then switch to the match's category",
+                        "  Esc           Clear search",
                         "",
                         " Scroll:",
                         "  ↑/↓           Line up/down",
-                        "  PgUp/PgDn     10 lines up/down",
+                        "  PgUp/PgDn     Page up/down",
+                        "  Ctrl+U/D      Half page up/down",
                         "  Home/End      Go to top/bottom",
-                        "",
-                        " Mouse click on a category (e.g., LogRenderer:) to filter",
-                    ].join("\n");
+                        "  ←/→           Scroll sideways (word wrap off)",
+                        "  G             Go to bottom and follow new lines",
+                        "  gg            Go to top",
+                        "  5↓ / 5↑       Count before ↑/↓ moves that many lines (j/k keep their own keys)",
+                        "  100G / 100gg  Go to line 100 (as numbered by L)",
+                    ];
+                    if self.mouse_enabled {
+                        help_lines.extend([
+                            "  Mouse wheel   3 lines up/down (1 with Ctrl)",
+                            "",
+                            " Mouse click on a category (e.g., LogRenderer:) to add/remove it from the filter",
+                            " Mouse click elsewhere on a line to select it (Esc deselects), double-click to copy it",
+                            " Mouse click on a line number to show that line in full",
+                            " Mouse click on a source tag (e.g., [game]) in a merged view to filter",
+                            " Ctrl/Alt+click on a line with Foo.cpp(123) to open it in your editor",
+                        ]);
+                    } else {
+                        help_lines.extend([
+                            "",
+                            " Mouse is off: drag to select text with your terminal, M turns it back on",
+                            " Filter with Shift+C (category picker), R (regex) or V (verbosity) instead of clicking",
+                        ]);
+                    }
+                    let help_text = help_lines.join("\n");
 
                     let popup = Paragraph::new(help_text)
                         .block(Block::default().title("Help (press H to close)").borders(Borders::ALL))
@@ -392,72 +1591,704 @@ impl App {
                     f.render_widget(Clear, area); // clear background
                     f.render_widget(popup, area);
                 }
+
+                // Stats popup overlay
+                if let Some(stats) = &self.stats {
+                    let w = (size.width as f32 * 0.8) as u16;
+                    let h = (size.height as f32 * 0.8) as u16;
+                    let area = Rect::new((size.width - w) / 2, (size.height - h) / 2, w, h);
+
+                    let header = Row::new(vec!["Category", "Lines", "Errors", "Warnings"])
+                        .style(Style::default().add_modifier(Modifier::BOLD));
+                    let mut rows: Vec<Row> = vec![Row::new(vec![
+                        "TOTAL".to_string(), stats.lines.to_string(), stats.errors.to_string(), stats.warnings.to_string(),
+                    ]).style(Style::default().fg(self.theme.warning_color()))];
+                    for r in stats.rows.iter().skip(self.stats_scroll) {
+                        let style = if r.errors > 0 { self.theme.error }
+                            else if r.warnings > 0 { self.theme.warning }
+                            else { Style::default() };
+                        let category = if self.hidden_categories.contains(&r.category) {
+                            format!("{} (hidden)", r.category)
+                        } else {
+                            r.category.clone()
+                        };
+                        rows.push(Row::new(vec![
+                            category, r.lines.to_string(), r.errors.to_string(), r.warnings.to_string(),
+                        ]).style(style));
+                    }
+                    let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(10), Constraint::Length(10), Constraint::Length(10)])
+                        .header(header)
+                        .block(Block::default()
+                            .title(match stats.hidden {
+                                0 => format!("Stats — {} categories (↑/↓ scroll, E: save JSON, Shift+E: CSV, I/Esc to close)", stats.rows.len()),
+                                n => format!("Stats — {} categories, {} lines hidden (↑/↓ scroll, E: save JSON, Shift+E: CSV, I/Esc to close)", stats.rows.len(), n),
+                            })
+                            .borders(Borders::ALL));
+                    f.render_widget(Clear, area);
+                    f.render_widget(table, area);
+                }
+
+                // Line detail overlay
+                if let Some(l) = &self.detail {
+                    let w = (size.width as f32 * 0.8) as u16;
+                    let h = (size.height as f32 * 0.8) as u16;
+                    let area = Rect::new((size.width - w) / 2, (size.height - h) / 2, w, h);
+                    let field = |name: &str, value: String| Line::from(vec![
+                        Span::styled(format!("{:<11}", name), Style::default().fg(Color::DarkGray)),
+                        Span::raw(value),
+                    ]);
+                    let mut text: Vec<Line> = Vec::new();
+                    if let Some(src) = &l.source {
+                        text.push(field("Source", src.clone()));
+                    }
+                    let time = match (&l.ts, l.ts_time) {
+                        (Some(ts), Some(t)) => format!("{}  ({})", ts, t.format("%Y-%m-%d %H:%M:%S%.3f")),
+                        (Some(ts), None) => ts.clone(),
+                        (None, Some(t)) => format!("{} (from the line above)", t.format("%Y-%m-%d %H:%M:%S%.3f")),
+                        (None, None) => "-".to_string(),
+                    };
+                    text.push(field("Time", time));
+                    let inherited = if l.continuation { " (from the line above)" } else { "" };
+                    text.push(field("Category", l.category.as_ref().map_or("-".to_string(), |c| format!("{}{}", c, inherited))));
+                    text.push(field("Verbosity", l.level.map_or("-".to_string(), |v| format!("{}{}", v.as_str(), inherited))));
+                    text.push(Line::raw(""));
+                    text.push(Line::styled(l.text.clone(), self.theme.line_style(l.color)));
+                    let popup = Paragraph::new(text)
+                        .block(Block::default().title("Line detail (↑/↓ scroll, Enter/Esc to close)").borders(Borders::ALL))
+                        .wrap(ratatui::widgets::Wrap { trim: false })
+                        .scroll((self.detail_scroll, 0));
+                    f.render_widget(Clear, area);
+                    f.render_widget(popup, area);
+                }
+
+                // Category picker overlay
+                if let Some(picker) = &self.category_picker {
+                    let w = (size.width as f32 * 0.6) as u16;
+                    let h = (size.height as f32 * 0.8) as u16;
+                    let area = Rect::new((size.width - w) / 2, (size.height - h) / 2, w, h);
+                    let items: Vec<ListItem> = picker.rows.iter().map(|(cat, count)| {
+                        let mark = if self.active_categories.contains(cat) { "[x] " } else { "[ ] " };
+                        ListItem::new(Line::from(vec![
+                            Span::styled(mark, Style::default().fg(self.theme.accent)),
+                            Span::styled(cat.clone(), self.category_style(cat)),
+                            Span::styled(format!("  {}", count), Style::default().fg(Color::DarkGray)),
+                        ]))
+                    }).collect();
+                    let list = List::new(items)
+                        .block(Block::default()
+                            .title(format!("Categories — {} (Enter: only this, Space: add/remove, C/Esc: close)", picker.rows.len()))
+                            .borders(Borders::ALL))
+                        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+                    f.render_widget(Clear, area);
+                    f.render_stateful_widget(list, area, &mut ratatui::widgets::ListState::default().with_selected(Some(picker.selected)));
+                }
+
+                // Quit confirmation
+                if self.confirm_quit {
+                    let what = if self.cook_active { "Cook" } else { "Live Coding compile" };
+                    let text = format!("{} in progress — quit anyway? y/N", what);
+                    let w = (text.chars().count() as u16 + 4).min(size.width);
+                    let area = Rect::new((size.width - w) / 2, (size.height / 2).saturating_sub(1), w, 3.min(size.height));
+                    let popup = Paragraph::new(text)
+                        .alignment(Alignment::Center)
+                        .style(Style::default().fg(self.theme.warning_color()).add_modifier(Modifier::BOLD))
+                        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(self.theme.warning_color())));
+                    f.render_widget(Clear, area);
+                    f.render_widget(popup, area);
+                }
+            }
+        }
+    }
+
+    // Footer hidden: a crash alert stays on the body's bottom border, a new status message shows there briefly
+    fn draw_toast(&mut self, f: &mut Frame, body: Rect) {
+        let now = Instant::now();
+        match (&self.last_error, &self.toast) {
+            (Some(msg), Some((shown, _))) if msg == shown => {}
+            (Some(msg), _) => self.toast = Some((msg.clone(), now)),
+            (None, _) => self.toast = None,
+        }
+        let (text, style) = if self.crash_alert {
+            ("CRASH DETECTED — press X to jump", self.theme.alert())
+        } else {
+            match &self.toast {
+                Some((msg, at)) if now.duration_since(*at) < TOAST_FOR => (msg.as_str(), Style::default().bg(Color::DarkGray).fg(Color::White)),
+                _ => return,
+            }
+        };
+        let w = (text.chars().count() as u16 + 2).min(body.width.saturating_sub(2));
+        if w == 0 || body.height == 0 { return; }
+        let area = Rect::new(body.x + 1, body.bottom() - 1, w, 1);
+        f.render_widget(Clear, area);
+        f.render_widget(Paragraph::new(format!(" {} ", text)).style(style), area);
+    }
+
+    // Ctrl+T: next built-in base theme, keeping the config's per-role overrides
+    fn cycle_theme(&mut self) {
+        if self.no_color {
+            self.last_error = Some("Colors are off (NO_COLOR or TERM=dumb)".to_string());
+            return;
+        }
+        let at = Theme::NAMES.iter().position(|&n| n == self.theme.name).unwrap_or(0);
+        let name = Theme::NAMES[(at + 1) % Theme::NAMES.len()];
+        let Ok(theme) = Theme::new(&self.cfg.theme, Some(name)) else { return; };
+        // a target without its own color follows the theme's accent
+        if self.current_accent == self.theme.header_project {
+            self.current_accent = theme.header_project;
+        } else if self.current_accent == self.theme.header_build {
+            self.current_accent = theme.header_build;
+        }
+        self.theme = theme;
+        self.config_generation += 1; // cached rows carry the old colors
+        self.last_error = Some(format!("Theme: {}", name));
+    }
+
+    // Shift+H: header and footer, footer only, neither, header only
+    fn cycle_chrome(&mut self) {
+        (self.show_header, self.show_footer) = match (self.show_header, self.show_footer) {
+            (true, true) => (false, true),
+            (false, true) => (false, false),
+            (false, false) => (true, false),
+            (true, false) => (true, true),
+        };
+        self.last_error = Some(match (self.show_header, self.show_footer) {
+            (true, true) => "Header and footer shown",
+            (false, true) => "Header hidden",
+            (false, false) => "Header and footer hidden",
+            (true, false) => "Footer hidden",
+        }.to_string());
+    }
+
+    // Shown instead of an empty selection list: where the config goes and what was searched
+    fn empty_config_guide(&self) -> Paragraph<'static> {
+        let dim = Style::default().fg(Color::DarkGray);
+        let mut text: Vec<Line> = vec![
+            Line::from(Span::styled("Nothing to watch yet", Style::default().fg(self.theme.warning_color()).add_modifier(Modifier::BOLD))),
+            Line::from(""),
+        ];
+        match self.config_paths.as_slice() {
+            [] => {
+                text.push(Line::from("No projects.json (or .toml/.yaml) was found. Looked for, in order:"));
+                for candidate in config_candidates() {
+                    text.push(Line::from(Span::styled(format!("  {}", candidate.display()), dim)));
+                }
+            }
+            paths => text.push(Line::from(format!("{} lists no projects or builds.", join_paths(paths)))),
+        }
+        text.extend([
+            Line::from(""),
+            Line::from("Minimal projects.json:"),
+            Line::from(Span::styled(r#"  { "projects": [ { "key": "game", "uproject": "D:/UE/MyGame/MyGame.uproject" } ] }"#, dim)),
+            Line::from(""),
+            Line::from("Running Unreal editors are discovered automatically every few seconds and will appear here."),
+            Line::from("You can also start ue-tui with a log file path: ue-tui path/to/Some.log"),
+        ]);
+        Paragraph::new(text)
+            .block(Block::default().title("Select target — Quit: Q").borders(Borders::ALL))
+            .wrap(ratatui::widgets::Wrap { trim: false })
+    }
+
+    // Row background for severity banding (B)
+    fn severity_band(&self, l: &LogLine) -> Option<Color> {
+        if !self.severity_bands { return None; }
+        let error = match l.level {
+            Some(Verbosity::Fatal) | Some(Verbosity::Error) => true,
+            Some(Verbosity::Warning) => false,
+            Some(_) => return None,
+            // unparsed lines go by the color the built-in keywords gave them
+            None => match l.color {
+                LineColor::Red | LineColor::LightRed => true,
+                LineColor::Yellow => false,
+                _ => return None,
+            },
+        };
+        Some(if error { self.theme.error_band } else { self.theme.warning_band })
+    }
+
+    // Log lines of one pane; `focus` is None outside split view
+    fn draw_body(&mut self, f: &mut Frame, area: Rect, filtered: &[usize], focus: Option<bool>) {
+        // max_width: the text, its borders and the minimap centered between equal margins
+        let area = match self.cfg.max_width.map(|w| w.max(20)) {
+            Some(max) if area.width > max.saturating_add(3) => Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Fill(1), Constraint::Length(max + 3), Constraint::Fill(1)].as_ref())
+                .split(area)[1],
+            _ => area,
+        };
+        // error-density minimap in the rightmost column, the bordered log block in the rest
+        let parts = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+            .split(area);
+        let (area, minimap_area) = (parts[0], parts[1]);
+        // remember both for mouse clicks
+        self.last_body_area = area;
+        self.last_minimap_area = minimap_area;
+
+        // Log body – compute visible slice based on scroll_from_bottom
+        let h = self.body_rows();
+        let total = filtered.len();
+        let end = total.saturating_sub(self.scroll_from_bottom);
+        let start = end.saturating_sub(h);
+        let slice = &filtered[start..end];
+        self.draw_minimap(f, minimap_area, filtered, start..end);
+
+        let mut lines_vec: Vec<Line> = Vec::with_capacity(slice.len() + 1);
+        if self.sticky_header {
+            lines_vec.push(self.sticky_banner(slice));
+        }
+        // content width inside the bordered block
+        let content_width = area.width.saturating_sub(2) as usize;
+        let gutter = self.gutter_width();
+        if !self.wrap_lines {
+            // keep at least one char of the longest visible message on screen
+            let longest = slice.iter().map(|&i| self.rendered_message(&self.lines[i]).chars().count()).max().unwrap_or(0);
+            self.h_scroll = self.h_scroll.min(longest.saturating_sub(1));
+        }
+        // lines read before scrolling away are dimmed while newer ones exist
+        let seen = self.last_seen_index.filter(|&s| s < self.lines.len());
+
+        // the rows only depend on the lines shown and the toggles below; reuse them while nothing moved
+        let mut hasher = DefaultHasher::new();
+        (self.lines_epoch, self.lines_dropped, self.filter_cache.borrow().generation, self.config_generation).hash(&mut hasher);
+        slice.hash(&mut hasher);
+        (content_width, gutter, self.h_scroll, self.wrap_lines, self.show_timestamp, self.show_elapsed, self.session_start).hash(&mut hasher);
+        self.show_raw.hash(&mut hasher);
+        (self.ansi_colors, self.severity_bands, self.show_highlights, self.collapse_duplicates).hash(&mut hasher);
+        (self.search_query.as_ref().map(|q| q.regex.as_str()), self.search_current, self.selected_line, seen).hash(&mut hasher);
+        let rows_key = hasher.finish();
+        if let Some((_, rows, row_lines)) = self.row_cache.as_ref().filter(|(key, ..)| *key == rows_key) {
+            lines_vec.extend(rows.iter().cloned());
+            self.last_row_lines.clone_from(row_lines);
+        } else {
+            let (rows, row_lines) = self.build_rows(slice, content_width, gutter, seen);
+            lines_vec.extend(rows.iter().cloned());
+            self.last_row_lines.clone_from(&row_lines);
+            self.row_cache = Some((rows_key, rows, row_lines));
+        }
+
+        // wrapped rows can outnumber the body; keep the newest ones (and the sticky banner)
+        let banner = self.sticky_header as usize;
+        let overflow = lines_vec.len().saturating_sub(h + banner);
+        if overflow > 0 {
+            lines_vec.drain(banner..banner + overflow);
+            self.last_row_lines.drain(..overflow);
+        }
+        self.draw_log_block(f, area, lines_vec, focus);
+    }
+
+    // Styled rows for the visible lines (wrapped lines give several), each with its line index
+    // and its row number within that line
+    fn build_rows(&self, slice: &[usize], content_width: usize, gutter: usize, seen: Option<usize>) -> (Vec<Line<'static>>, Vec<RowRef>) {
+        let match_style = self.theme.highlight;
+        let mut rows: Vec<Line<'static>> = Vec::with_capacity(slice.len());
+        let mut row_lines: Vec<RowRef> = Vec::with_capacity(slice.len());
+        for &idx in slice.iter() {
+            let l = &self.lines[idx];
+            let mut spans: Vec<Span> = Vec::new();
+            let mut prefix_len = 0usize;
+            if gutter > 0 {
+                prefix_len += gutter;
+                spans.push(Span::styled(format!("{:>w$} ", self.lines_dropped + idx + 1, w = gutter - 1), Style::default().fg(Color::DarkGray)));
+            }
+            if let Some(src) = &l.source {
+                let src_part = format!("[{}] ", src);
+                prefix_len += src_part.chars().count();
+                spans.push(Span::styled(src_part, Style::default().fg(source_color(src))));
+            }
+            // raw mode shows the line exactly as read, keeping only its color
+            if let Some(ts_part) = self.timestamp_part(l).filter(|_| !self.show_raw) {
+                prefix_len += ts_part.chars().count();
+                spans.push(Span::styled(ts_part, self.theme.timestamp));
+            }
+            if l.continuation && !self.show_raw {
+                // inherited category and verbosity aren't repeated; indent under the header instead
+                prefix_len += 2;
+                spans.push(Span::raw("  "));
+            } else if let Some(cat) = l.category.as_ref().filter(|_| !self.show_raw) {
+                let cat_part = format!("{}:", cat);
+                prefix_len += cat_part.chars().count();
+                spans.push(Span::styled(cat_part, self.category_style(cat).add_modifier(Modifier::UNDERLINED)));
+                prefix_len += 1; // space after category
+                spans.push(Span::raw(" "));
+            }
+            if let Some(level) = l.level.filter(|_| !l.continuation && !self.show_raw) {
+                let level_part = format!("{}: ", level.as_str());
+                prefix_len += level_part.chars().count();
+                spans.push(Span::styled(level_part, self.theme.line_style(l.color).add_modifier(Modifier::BOLD)));
+            }
+            if self.collapse_duplicates && !self.show_raw {
+                let count = self.repeat_count(idx);
+                if count > 1 {
+                    let counter = format!("(x{}) ", count);
+                    prefix_len += counter.chars().count();
+                    spans.push(Span::styled(counter, Style::default().fg(Color::DarkGray).add_modifier(Modifier::BOLD)));
+                }
+            }
+            // message (or original text if no parsed parts)
+            let msg = self.rendered_message(l);
+            let text_style = self.theme.line_style(l.color);
+            // color offsets are relative to the parsed message
+            let ansi: &[(usize, LineColor)] = if self.ansi_colors && !self.show_raw { &l.ansi } else { &[] };
+            if self.wrap_lines {
+                spans.extend(ansi_spans(msg, 0, ansi, text_style));
+            } else {
+                // the prefix above stays frozen; only the message scrolls, behind a marker
+                // showing where the hidden part starts
+                let mut remaining = content_width.saturating_sub(prefix_len);
+                if self.h_scroll > 0 && remaining > 1 {
+                    spans.push(Span::styled("«", Style::default().fg(Color::DarkGray)));
+                    remaining -= 1;
+                }
+                let shifted: String = msg.chars().skip(self.h_scroll).collect();
+                let msg_len = shifted.chars().count();
+                if msg_len > remaining {
+                    // ensure room for ellipsis
+                    if remaining >= 3 { remaining -= 3; }
+                    let taken: String = shifted.chars().take(remaining).collect();
+                    spans.extend(ansi_spans(&taken, self.h_scroll, ansi, text_style));
+                    spans.push(Span::styled("...", text_style));
+                } else {
+                    spans.extend(ansi_spans(&shifted, self.h_scroll, ansi, text_style));
+                }
+            }
+            if self.show_highlights {
+                for hl in &self.highlights {
+                    spans = highlight_regex(spans, &hl.regex, Style::default().fg(hl.color).add_modifier(Modifier::BOLD));
+                }
+            }
+            if let Some(q) = &self.search_query {
+                let style = if self.search_current == Some(idx) { match_style.add_modifier(Modifier::BOLD) } else { match_style };
+                spans = highlight_regex(spans, &q.regex, style);
+            }
+            let mut line_style = match self.severity_band(l) {
+                Some(bg) => Style::default().bg(bg),
+                None => Style::default(),
+            };
+            if l.crash {
+                line_style = self.theme.alert();
+            }
+            if self.selected_line == Some(idx) {
+                line_style = Style::default().bg(Color::DarkGray);
+            }
+            if seen.is_some_and(|s| idx < s) {
+                line_style = line_style.add_modifier(Modifier::DIM);
+            }
+            if self.wrap_lines {
+                // continuation rows start under the message rather than flush-left
+                let indent = if self.cfg.wrap_indent.unwrap_or(true) { prefix_len.min(content_width / 2) } else { 0 };
+                let wrapped = wrap_spans(&spans, content_width, indent);
+                row_lines.extend((0..wrapped.len()).map(|r| (idx, r)));
+                rows.extend(wrapped.into_iter().map(|row| fill_row(row.style(line_style), content_width)));
+            } else {
+                row_lines.push((idx, 0));
+                rows.push(fill_row(Line::from(spans).style(line_style), content_width));
+            }
+        }
+        (rows, row_lines)
+    }
+
+    fn draw_log_block(&self, f: &mut Frame, area: Rect, lines_vec: Vec<Line>, focus: Option<bool>) {
+        // split view: the focused pane gets a highlighted border, each title names its categories
+        let mut block = Block::default().borders(Borders::ALL);
+        if self.cfg.bell() == Some(Bell::Visual) && self.last_bell.is_some_and(|t| t.elapsed() < FLASH_FOR) {
+            block = block.border_style(Style::default().fg(self.theme.error_color()).add_modifier(Modifier::BOLD | Modifier::REVERSED));
+        }
+        match focus {
+            None => block = block.title("Logs"),
+            Some(focused) => {
+                let mut cats: Vec<&str> = self.active_categories.iter().map(|c| c.as_str()).collect();
+                cats.sort_unstable();
+                let label = if cats.is_empty() { "all categories".to_string() } else { cats.join(", ") };
+                if focused {
+                    block = block.title(format!("Logs — {} (Tab: switch pane)", label))
+                        .border_style(Style::default().fg(self.theme.warning_color()));
+                } else {
+                    block = block.title(format!("Logs — {}", label));
+                }
             }
         }
+        let body = Paragraph::new(lines_vec)
+            .block(block)
+            .scroll((0, 0));
+        f.render_widget(body, area);
     }
 
-    fn on_key(&mut self, kind: KeyEventKind, key: KeyCode, _ctrl: bool) -> Result<Action> {
+    // One cell per slice of the filtered buffer, colored by its worst line; the viewport is highlighted
+    fn draw_minimap(&self, f: &mut Frame, area: Rect, filtered: &[usize], viewport: std::ops::Range<usize>) {
+        // align with the content rows inside the log block's borders
+        let rows = area.height.saturating_sub(2) as usize;
+        if rows == 0 || filtered.is_empty() { return; }
+        let cells: Vec<Line> = (0..rows).map(|r| {
+            let range = minimap_cell_range(r, rows, filtered.len());
+            let worst = filtered[range.clone()].iter().filter_map(|&i| line_severity(&self.lines[i])).min();
+            let (symbol, color) = match worst {
+                Some(Verbosity::Fatal | Verbosity::Error) => ("█", self.theme.error_color()),
+                Some(Verbosity::Warning) => ("█", self.theme.warning_color()),
+                _ if range.is_empty() => (" ", Color::Reset),
+                _ => ("│", Color::DarkGray),
+            };
+            let mut style = Style::default().fg(color);
+            if range.start < viewport.end && viewport.start < range.end.max(range.start + 1) {
+                style = style.bg(Color::Gray);
+            }
+            Line::from(Span::styled(symbol, style))
+        }).collect();
+        f.render_widget(Paragraph::new(cells), Rect::new(area.x, area.y + 1, area.width, rows as u16));
+    }
+
+    fn on_key(&mut self, kind: KeyEventKind, key: KeyCode, ctrl: bool) -> Result<Action> {
         match self.mode {
             Mode::Select => match key {
+                // while filtering, typed characters go to the query; Enter, Space and the arrows still work
+                KeyCode::Esc if self.select_query.is_some() => { self.select_query = None; }
+                KeyCode::Backspace if kind == KeyEventKind::Press && self.select_query.is_some() => self.edit_select_query(None),
+                KeyCode::Char(c) if kind == KeyEventKind::Press && self.select_query.is_some() && c != ' ' && !ctrl => {
+                    self.edit_select_query(Some(c));
+                }
+                KeyCode::Char(_) if self.select_query.is_some() && key != KeyCode::Char(' ') => { /* ignore repeats/releases */ }
                 KeyCode::Char('q') | KeyCode::Esc => return Ok(Action::Quit),
-                KeyCode::Up if kind == KeyEventKind::Press => { if self.selected > 0 { self.selected -= 1; } }
-                KeyCode::Down if kind == KeyEventKind::Press => { let total = self.cfg.projects.len() + self.cfg.builds.len(); if self.selected + 1 < total { self.selected += 1; } }
-                KeyCode::Enter if kind == KeyEventKind::Press => {
-                    let pcount = self.cfg.projects.len();
-                    if self.selected < pcount {
-                        let project = self.cfg.projects[self.selected].clone();
-                        let log_path = log_path_from_uproject(&project.uproject)?;
-                        let name = project.name_or_key();
-                        self.current_is_build = false;
-                        self.start_tail(name, log_path)?;
+                KeyCode::Char('/') if kind == KeyEventKind::Press => { self.select_query = Some(String::new()); }
+                KeyCode::Up if kind == KeyEventKind::Press => self.step_selection(false),
+                KeyCode::Down if kind == KeyEventKind::Press => self.step_selection(true),
+                KeyCode::Char('b') | KeyCode::Char('B') if kind == KeyEventKind::Press => {
+                    self.tail_from_start = !self.tail_from_start;
+                }
+                KeyCode::Char('p') | KeyCode::Char('P') if kind == KeyEventKind::Press => {
+                    self.path_style = self.path_style.next();
+                }
+                KeyCode::Char(' ') if kind == KeyEventKind::Press && self.selected_is_shown() => {
+                    if let Some(pos) = self.checked.iter().position(|&i| i == self.selected) {
+                        self.checked.remove(pos);
                     } else {
-                        let idx = self.selected - pcount;
-                        if let Some(build) = self.cfg.builds.get(idx).cloned() {
-                            let log_path = log_path_from_exe(&build.exe)?;
-                            let name = build.name_or_key();
-                            self.current_is_build = true;
-                            self.start_tail(name, log_path)?;
-                        }
+                        self.checked.push(self.selected);
                     }
-                    self.mode = Mode::View;
                 }
+                KeyCode::Enter if kind == KeyEventKind::Press && self.selected_is_shown() => {
+                    self.select_query = None;
+                    let picked = if self.checked.is_empty() { vec![self.selected] } else { self.checked.clone() };
+                    self.open_targets(picked)?;
+                }
+                KeyCode::Char(c @ '1'..='9') if kind == KeyEventKind::Press => self.open_numbered_target(c)?,
                 _ => {}
             },
             Mode::View => {
+                // Quit confirmation during a cook/compile takes every key
+                if self.confirm_quit {
+                    if kind == KeyEventKind::Release { return Ok(Action::Continue); }
+                    self.confirm_quit = false;
+                    if matches!(key, KeyCode::Char('y') | KeyCode::Char('Y')) { return Ok(Action::Quit); }
+                    return Ok(Action::Continue);
+                }
                 // If help popup is visible, treat keys as modal
                 if self.show_help {
                     match (kind, key) {
                         (KeyEventKind::Press, KeyCode::Char('h')) | (KeyEventKind::Press, KeyCode::Esc) => { self.show_help = false; }
-                        (KeyEventKind::Press, KeyCode::Char('q')) => return Ok(Action::Quit),
+                        (KeyEventKind::Press, KeyCode::Char('q')) => return Ok(self.request_quit()),
+                        _ => {}
+                    }
+                    return Ok(Action::Continue);
+                }
+                // Stats popup is modal too
+                if let Some(stats) = &self.stats {
+                    if kind == KeyEventKind::Release { return Ok(Action::Continue); }
+                    match key {
+                        KeyCode::Char('i') | KeyCode::Esc => { self.stats = None; }
+                        KeyCode::Char('e') => self.export_stats(false),
+                        KeyCode::Char('E') => self.export_stats(true),
+                        KeyCode::Char('q') => return Ok(self.request_quit()),
+                        KeyCode::Up => { self.stats_scroll = self.stats_scroll.saturating_sub(1); }
+                        KeyCode::Down if self.stats_scroll + 1 < stats.rows.len() => { self.stats_scroll += 1; }
+                        _ => {}
+                    }
+                    return Ok(Action::Continue);
+                }
+                // ...the line detail
+                if self.detail.is_some() {
+                    if kind == KeyEventKind::Release { return Ok(Action::Continue); }
+                    match key {
+                        KeyCode::Enter | KeyCode::Esc => { self.detail = None; }
+                        KeyCode::Char('q') => return Ok(self.request_quit()),
+                        KeyCode::Up => { self.detail_scroll = self.detail_scroll.saturating_sub(1); }
+                        KeyCode::Down => { self.detail_scroll = self.detail_scroll.saturating_add(1); }
+                        KeyCode::PageUp => { self.detail_scroll = self.detail_scroll.saturating_sub(10); }
+                        KeyCode::PageDown => { self.detail_scroll = self.detail_scroll.saturating_add(10); }
+                        KeyCode::Home => { self.detail_scroll = 0; }
+                        _ => {}
+                    }
+                    return Ok(Action::Continue);
+                }
+                // ...and so is the category picker
+                if let Some(picker) = &mut self.category_picker {
+                    if kind == KeyEventKind::Release { return Ok(Action::Continue); }
+                    match key {
+                        KeyCode::Char('C') | KeyCode::Esc => { self.category_picker = None; }
+                        KeyCode::Char('q') => return Ok(self.request_quit()),
+                        KeyCode::Up => { picker.selected = picker.selected.saturating_sub(1); }
+                        KeyCode::Down if picker.selected + 1 < picker.rows.len() => { picker.selected += 1; }
+                        KeyCode::PageUp => { picker.selected = picker.selected.saturating_sub(10); }
+                        KeyCode::PageDown => { picker.selected = (picker.selected + 10).min(picker.rows.len().saturating_sub(1)); }
+                        KeyCode::Char(' ') | KeyCode::Enter => {
+                            if let Some((cat, _)) = picker.rows.get(picker.selected) {
+                                let cat = cat.clone();
+                                let anchor = self.view_anchor();
+                                if key == KeyCode::Enter {
+                                    self.active_categories = HashSet::from([cat]);
+                                    self.category_picker = None;
+                                } else if !self.active_categories.remove(&cat) {
+                                    self.active_categories.insert(cat);
+                                }
+                                self.restore_view_anchor(anchor);
+                            }
+                        }
                         _ => {}
                     }
                     return Ok(Action::Continue);
                 }
+                // Footer input line captures all keys while open
+                if self.input.is_some() {
+                    self.on_input_key(kind, key, ctrl);
+                    return Ok(Action::Continue);
+                }
+                if kind == KeyEventKind::Press && !ctrl && self.on_motion_key(key)? {
+                    return Ok(Action::Continue);
+                }
                 match key {
-                    KeyCode::Char('q') | KeyCode::Esc => return Ok(Action::Quit),
+                    // half a page, as in less/vim
+                    KeyCode::Char('u') if ctrl => self.scroll_up(self.page_size().div_ceil(2)),
+                    KeyCode::Char('d') if ctrl => self.scroll_down(self.page_size().div_ceil(2)),
+                    KeyCode::Char('f') if ctrl && kind == KeyEventKind::Press => self.forget_target_filters(),
+                    KeyCode::Char('t') if ctrl && kind == KeyEventKind::Press => self.cycle_theme(),
+                    KeyCode::Char('t') if ctrl => { /* ignore repeats/releases */ }
+                    KeyCode::Char('f') if ctrl => { /* ignore repeats/releases */ }
+                    KeyCode::Esc if self.search_query.is_some() => { self.clear_search(); }
+                    KeyCode::Esc if self.selected_line.is_some() => { self.selected_line = None; }
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(self.request_quit()),
+                    KeyCode::Char('/') if kind == KeyEventKind::Press => {
+                        self.input = Some(InputLine::new(Prompt::Search, String::new()));
+                    }
+                    KeyCode::Char('r') if kind == KeyEventKind::Press => {
+                        let buffer = self.active_regex_filter.as_ref().map(|re| re.as_str().to_string()).unwrap_or_default();
+                        self.input = Some(InputLine::new(Prompt::Regex, buffer));
+                    }
+                    KeyCode::Char('R') if kind == KeyEventKind::Press => {
+                        self.show_raw = !self.show_raw;
+                        self.h_scroll = 0;
+                        self.last_error = Some(format!("{} lines", if self.show_raw { "Raw" } else { "Parsed" }));
+                    }
+                    KeyCode::Char('J') if kind == KeyEventKind::Press => {
+                        let buffer = match &self.active_time_filter {
+                            Some((from, Some(to))) => format!("{}..{}", from, to),
+                            Some((from, None)) => from.to_string(),
+                            None => String::new(),
+                        };
+                        self.input = Some(InputLine::new(Prompt::TimeRange, buffer));
+                    }
+                    KeyCode::Char('j') if kind == KeyEventKind::Press => {
+                        self.input = Some(InputLine::new(Prompt::JumpTime, String::new()));
+                    }
+                    KeyCode::Char('n') if kind == KeyEventKind::Press && self.search_query.is_some() => self.search_step(true),
+                    KeyCode::Char('N') if kind == KeyEventKind::Press && self.search_query.is_some() => self.search_step(false),
                     KeyCode::Char('h') if kind == KeyEventKind::Press => { self.show_help = true; }
-                    KeyCode::Char('c') => { let _ = self.tx_cmd.send(Cmd::Clear); self.lines.clear(); self.scroll_from_bottom = 0; }
+                    KeyCode::Char('H') if kind == KeyEventKind::Press => self.cycle_chrome(),
+                    KeyCode::Enter if kind == KeyEventKind::Press => { self.open_detail(None); }
+                    KeyCode::Char('i') if kind == KeyEventKind::Press => {
+                        self.stats = Some(self.compute_stats());
+                        self.stats_scroll = 0;
+                    }
+                    KeyCode::Char('C') if kind == KeyEventKind::Press => self.open_category_picker(),
+                    KeyCode::Char('|') if kind == KeyEventKind::Press => {
+                        self.split = match self.split {
+                            Some(_) => None,
+                            None => Some(SplitPane::new()),
+                        };
+                    }
+                    KeyCode::Tab if kind == KeyEventKind::Press => self.switch_pane(),
+                    KeyCode::Char('p') if kind == KeyEventKind::Press => self.toggle_pause(),
+                    KeyCode::Char('c') => self.clear_view(),
+                    KeyCode::Char('y') if kind == KeyEventKind::Press => self.copy_to_clipboard(),
+                    KeyCode::Char('o') if kind == KeyEventKind::Press => match self.selected_line {
+                        Some(idx) => self.open_source_ref(idx),
+                        None => self.last_error = Some("Select a line first (click it), then press o".to_string()),
+                    },
+                    KeyCode::Char('O') if kind == KeyEventKind::Press => self.reveal_log_folder(),
+                    KeyCode::Char('x') if kind == KeyEventKind::Press => self.jump_to_crash(),
                     KeyCode::Char('t') if kind == KeyEventKind::Press => { self.show_timestamp = !self.show_timestamp; }
+                    KeyCode::Char('T') if kind == KeyEventKind::Press => {
+                        self.show_elapsed = !self.show_elapsed;
+                        if self.show_elapsed { self.show_timestamp = true; }
+                    }
                     KeyCode::Char('t') => { /* ignore repeats/releases for toggle */ }
-                    KeyCode::Char('w') if kind == KeyEventKind::Press => { self.wrap_lines = !self.wrap_lines; }
+                    KeyCode::Char('k') if kind == KeyEventKind::Press => { self.sticky_header = !self.sticky_header; }
+                    KeyCode::Char('d') if kind == KeyEventKind::Press => {
+                        let anchor = self.view_anchor();
+                        self.collapse_duplicates = !self.collapse_duplicates;
+                        self.restore_view_anchor(anchor);
+                        if let Some(pane) = &mut self.split { pane.scroll_from_bottom = 0; }
+                    }
+                    KeyCode::Char('m') if kind == KeyEventKind::Press => {
+                        self.mouse_enabled = !self.mouse_enabled;
+                        self.last_error = Some(format!("Mouse {}", if self.mouse_enabled { "on" } else { "off: select text with the terminal" }));
+                    }
+                    KeyCode::Char('a') if kind == KeyEventKind::Press => {
+                        self.ansi_colors = !self.ansi_colors;
+                        self.last_error = Some(format!("ANSI colors from the log {}", if self.ansi_colors { "shown" } else { "stripped" }));
+                    }
+                    KeyCode::Char('b') if kind == KeyEventKind::Press => {
+                        self.severity_bands = !self.severity_bands;
+                        self.last_error = Some(format!("Error/warning row backgrounds {}", if self.severity_bands { "on" } else { "off" }));
+                    }
+                    KeyCode::Char('u') if kind == KeyEventKind::Press => {
+                        self.show_highlights = !self.show_highlights;
+                        self.last_error = Some(format!("Keyword highlights {}", if self.show_highlights { "on" } else { "off" }));
+                    }
+                    KeyCode::Char('l') | KeyCode::Char('#') if kind == KeyEventKind::Press => { self.show_line_numbers = !self.show_line_numbers; }
+                    KeyCode::Char('w') if kind == KeyEventKind::Press => { self.wrap_lines = !self.wrap_lines; self.h_scroll = 0; }
+                    KeyCode::Left if !self.wrap_lines => { self.h_scroll = self.h_scroll.saturating_sub(8); }
+                    KeyCode::Right if !self.wrap_lines => { self.h_scroll += 8; }
                     KeyCode::Char('w') => { /* ignore repeats/releases for toggle */ }
-                    KeyCode::Char('f') => { self.active_category_filter = None; }
-                    KeyCode::Char('s') => { 
-                        // Return to project selection menu
-                        self.mode = Mode::Select; 
-                        self.current_name = None;
-                        self.current_is_build = false;
-                        self.lines.clear();
-                        self.scroll_from_bottom = 0;
-                        self.last_error = None;
-                        self.active_category_filter = None;
+                    KeyCode::Char('e') if kind == KeyEventKind::Press => self.export_view(false),
+                    KeyCode::Char('E') if kind == KeyEventKind::Press => self.export_view(true),
+                    KeyCode::Char('v') if kind == KeyEventKind::Press => self.step_min_verbosity(true),
+                    KeyCode::Char('V') if kind == KeyEventKind::Press => self.step_min_verbosity(false),
+                    KeyCode::Char('z') if kind == KeyEventKind::Press => {
+                        self.group_multiline = !self.group_multiline;
+                        let rules = self.color_rules.clone();
+                        regroup_lines(&mut self.lines, self.group_multiline, &rules);
+                        self.lines_epoch += 1;
+                        for state in self.background.values_mut() {
+                            regroup_lines(&mut state.lines, self.group_multiline, &rules);
+                        }
+                        self.last_error = Some(format!("Multi-line grouping {}", if self.group_multiline { "on" } else { "off" }));
+                    }
+                    KeyCode::Char('F') if kind == KeyEventKind::Press => {
+                        let anchor = self.view_anchor();
+                        self.show_hidden = !self.show_hidden;
+                        self.restore_view_anchor(anchor);
+                        self.last_error = Some(if self.hidden_categories.is_empty() {
+                            "No hidden_categories configured".to_string()
+                        } else if self.show_hidden {
+                            format!("Showing {} hidden categories", self.hidden_categories.len())
+                        } else {
+                            format!("Hiding {} categories", self.hidden_categories.len())
+                        });
+                    }
+                    KeyCode::Char('f') if kind == KeyEventKind::Press => {
+                        let anchor = self.view_anchor();
+                        self.active_categories.clear();
+                        self.active_regex_filter = None;
+                        self.active_source_filter = None;
+                        self.active_time_filter = None;
+                        self.restore_view_anchor(anchor);
                     }
+                    KeyCode::F(n) if kind == KeyEventKind::Press => self.apply_filter_preset(n),
+                    KeyCode::Char('s') => self.leave_view(),
                     KeyCode::Up => self.scroll_up(1),
                     KeyCode::Down => self.scroll_down(1),
-                    KeyCode::PageUp => self.scroll_up(10),
-                    KeyCode::PageDown => self.scroll_down(10),
+                    KeyCode::PageUp => self.scroll_up(self.page_size()),
+                    KeyCode::PageDown => self.scroll_down(self.page_size()),
                     KeyCode::Home => { self.scroll_from_bottom = self.lines.len(); } // go to top
-                    KeyCode::End => { self.scroll_from_bottom = 0; } // bottom
+                    KeyCode::End | KeyCode::Char('G') => { self.scroll_to_bottom(); } // bottom, resume following
                     _ => {}
                 }
             },
@@ -465,134 +2296,1714 @@ impl App {
         Ok(Action::Continue)
     }
 
+    // Counts and `gg` in front of the scroll keys: `5↓`, `20↑`, `gg`, `100G`/`100gg` (go to line 100).
+    // j and k are taken by jump-to-time and the sticky header, so only the arrows take a count.
+    // Returns false for keys that are not part of a motion; they drop any pending count, but a
+    // lone 1-9 switches targets first and the key then acts there.
+    fn on_motion_key(&mut self, key: KeyCode) -> Result<bool> {
+        let pending = self.pending_motion.take();
+        let count = pending.map_or(0, |p| p.count);
+        let g = pending.is_some_and(|p| p.g);
+        let start = |count: usize, g: bool| Some(PendingMotion { count, g, since: Instant::now() });
+        match key {
+            KeyCode::Char(c @ '0'..='9') if !g && (count > 0 || c != '0') => {
+                let digit = c.to_digit(10).unwrap_or(0) as usize;
+                self.pending_motion = start(count.saturating_mul(10).saturating_add(digit), false);
+            }
+            KeyCode::Char('g') if g => self.go_to_line(count.max(1)),
+            KeyCode::Char('g') => self.pending_motion = start(count, true),
+            KeyCode::Char('G') if count > 0 && !g => self.go_to_line(count),
+            KeyCode::Down if count > 0 && !g => self.scroll_down(count),
+            KeyCode::Up if count > 0 && !g => self.scroll_up(count),
+            KeyCode::Esc if pending.is_some() => {} // drop the count
+            _ => {
+                if let Some(p) = pending {
+                    self.switch_on_lone_digit(p)?;
+                }
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn expire_pending_motion(&mut self) -> Result<()> {
+        let Some(p) = self.pending_motion else { return Ok(()); };
+        if p.since.elapsed() < PENDING_MOTION_TIMEOUT { return Ok(()); }
+        self.pending_motion = None;
+        self.switch_on_lone_digit(p)
+    }
+
+    // A lone 1-9 that no motion followed switches targets, as it did before counts existed
+    fn switch_on_lone_digit(&mut self, p: PendingMotion) -> Result<()> {
+        match char::from_digit(p.count as u32, 10) {
+            Some(digit) if !p.g && p.count > 0 && self.mode == Mode::View => self.open_numbered_target(digit),
+            _ => Ok(()),
+        }
+    }
+
+    // Bring buffer line `n` (1-based, as in the gutter) to the top of the view, or the next line the filters keep
+    fn go_to_line(&mut self, n: usize) {
+        let filtered = self.filtered_indices();
+        if filtered.is_empty() { return; }
+        let pos = filtered.iter().position(|&i| self.lines_dropped + i + 1 >= n).unwrap_or(filtered.len() - 1);
+        self.show_from(&filtered, pos);
+    }
+
+    // Select `filtered[pos]` and scroll so it is the first row on screen
+    fn show_from(&mut self, filtered: &[usize], pos: usize) {
+        self.selected_line = Some(filtered[pos]);
+        let below = filtered.len() - pos;
+        self.scroll_from_bottom = below.saturating_sub(self.body_rows());
+    }
+
+    // Quit at once when idle; while a cook or Live Coding compile runs, ask first
+    fn request_quit(&mut self) -> Action {
+        if self.cook_active || matches!(self.live_coding, Some(LiveCoding::Compiling)) {
+            self.confirm_quit = true;
+            Action::Continue
+        } else {
+            Action::Quit
+        }
+    }
+
     fn on_mouse(&mut self, m: crossterm::event::MouseEvent) {
         use crossterm::event::{MouseButton, MouseEventKind};
-        if self.mode != Mode::View { return; }
-        // Only react to left button down
+        if self.mode != Mode::View || !self.mouse_enabled { return; }
+        // in split view, clicking or scrolling the other pane focuses it first (hovering doesn't)
+        let pos = Position::new(m.column, m.row);
+        let touch = matches!(m.kind, MouseEventKind::Down(_) | MouseEventKind::ScrollUp | MouseEventKind::ScrollDown);
+        if touch && self.split.as_ref().is_some_and(|split| split.area.contains(pos) || split.minimap_area.contains(pos)) {
+            self.switch_pane();
+        }
+        let fine = m.modifiers.contains(KeyModifiers::CONTROL);
+        match m.kind {
+            // Ctrl+wheel for single-line steps
+            MouseEventKind::ScrollUp => { self.scroll_up(if fine { 1 } else { 3 }); return; }
+            MouseEventKind::ScrollDown => { self.scroll_down(if fine { 1 } else { 3 }); return; }
+            _ => {}
+        }
+        // Otherwise only react to left button down
         if let MouseEventKind::Down(MouseButton::Left) = m.kind {
+            // only a click straight after another on the same line-selecting cell makes a double-click
+            let last_click = self.last_click.take();
+            // minimap: center the view on the clicked region
+            let map = self.last_minimap_area;
+            if map.contains(pos) && m.row > map.y && m.row < map.y + map.height - 1 {
+                let total = self.filtered_indices().len();
+                let range = minimap_cell_range((m.row - map.y - 1) as usize, map.height as usize - 2, total);
+                let end = ((range.start + range.end) / 2 + self.body_rows() / 2).min(total);
+                self.scroll_from_bottom = total - end;
+                return;
+            }
+            // the "Watching: …" message copies the full paths behind it
+            if self.last_status_area.contains(pos) && self.last_error.as_deref().is_some_and(|e| e.starts_with("Watching: ")) {
+                if !self.watching.is_empty() {
+                    let paths = self.watching.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join("\n");
+                    let what = if self.watching.len() == 1 { "log path" } else { "log paths" };
+                    self.set_clipboard(paths, what);
+                }
+                return;
+            }
             // Check click within log body content area (account for borders of block)
             let body = self.last_body_area;
-            if m.column >= body.x + 1 && m.column < body.x + body.width - 1 &&
-               m.row >= body.y + 1 && m.row < body.y + body.height - 1 {
-                // Build filtered list
-                let filtered_indices: Vec<usize> = if let Some(cat) = &self.active_category_filter {
-                    self.lines.iter().enumerate().filter(|(_, l)| l.category.as_deref() == Some(cat.as_str())).map(|(i, _)| i).collect()
-                } else { (0..self.lines.len()).collect() };
-                let h = (body.height.saturating_sub(2)) as usize; // content height inside borders
-                let total = filtered_indices.len();
-                let end = total.saturating_sub(self.scroll_from_bottom);
-                let start = end.saturating_sub(h);
-                let offset_row = (m.row - (body.y + 1)) as usize;
-                let idx_in_view = start + offset_row;
-                if idx_in_view < end && idx_in_view < filtered_indices.len() {
-                    let line_idx = filtered_indices[idx_in_view];
-                    if let Some(cat) = &self.lines[line_idx].category {
+            if m.column > body.x && m.column < body.x + body.width - 1 &&
+               m.row > body.y && m.row < body.y + body.height - 1 {
+                // the sticky banner occupies the first content row; the rest map to lines as last drawn
+                let Some(offset_row) = ((m.row - (body.y + 1)) as usize).checked_sub(self.sticky_header as usize) else { return; };
+                let hit = self.last_row_lines.get(offset_row).copied().filter(|&(idx, _)| idx < self.lines.len());
+                if let Some((line_idx, row_in_line)) = hit {
+                    // Ctrl/Alt-click: jump to the file(line) the message points at
+                    if m.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                        self.open_source_ref(line_idx);
+                        return;
+                    }
+                    // the prefix columns are only on a line's first row; wrapped rows below it just select
+                    let content_x = match ((m.column - (body.x + 1)) as usize).checked_sub(self.gutter_width()) {
+                        _ if row_in_line > 0 => usize::MAX,
+                        Some(x) => x,
+                        // a click on the line number opens the detail popup
+                        None => {
+                            self.open_detail(Some(line_idx));
+                            return;
+                        }
+                    };
+                    let src_len = self.lines[line_idx].source.as_ref()
+                        .map(|src| format!("[{}] ", src).chars().count())
+                        .unwrap_or(0);
+                    if let Some(src) = &self.lines[line_idx].source {
+                        // "[key]" without the trailing space
+                        if content_x < src_len - 1 {
+                            let src = src.clone();
+                            let anchor = self.view_anchor();
+                            self.active_source_filter = Some(src);
+                            self.restore_view_anchor(anchor);
+                            return;
+                        }
+                    }
+                    if let Some(cat) = self.lines[line_idx].category.as_ref().filter(|_| !self.lines[line_idx].continuation && !self.show_raw) {
                         // Determine x range of category span in content coordinates using same logic as draw()
-                        let ts_len = if self.show_timestamp {
-                            if let Some(ts) = &self.lines[line_idx].ts {
-                                let ts_part = format!("[{}] ", ts);
-                                ts_part.chars().count()
-                            } else { 0 }
-                        } else { 0 };
+                        let ts_len = src_len + self.timestamp_part(&self.lines[line_idx]).map_or(0, |t| t.chars().count());
                         let cat_part = format!("{}:", cat);
                         let cat_len = cat_part.chars().count();
                         let cat_start = ts_len;
                         let cat_end = ts_len + cat_len;
-                        let content_x = (m.column - (body.x + 1)) as usize;
                         if content_x >= cat_start && content_x < cat_end {
-                            self.active_category_filter = Some(cat.clone());
-                            self.scroll_from_bottom = 0; // jump to bottom on new filter
+                            // toggle membership in the category set
+                            let cat = cat.clone();
+                            let anchor = self.view_anchor();
+                            if !self.active_categories.remove(&cat) {
+                                self.active_categories.insert(cat);
+                            }
+                            self.restore_view_anchor(anchor);
+                            return;
                         }
                     }
+                    // Anywhere else on a line: (un)select it for copying; a double-click copies it
+                    let now = Instant::now();
+                    if last_click.is_some_and(|(t, col, row)| now.duration_since(t) < DOUBLE_CLICK && (col, row) == (m.column, m.row)) {
+                        self.selected_line = Some(line_idx);
+                        self.copy_to_clipboard();
+                        return;
+                    }
+                    self.last_click = Some((now, m.column, m.row));
+                    self.selected_line = if self.selected_line == Some(line_idx) { None } else { Some(line_idx) };
                 }
             }
         }
     }
 
-    fn push_line(&mut self, line: LogLine) {
+    fn handle_event(&mut self, event: TailEvent) {
+        match event {
+            TailEvent::Line(l) => {
+                self.queued.fetch_sub(1, Ordering::Relaxed);
+                self.receive_line(l);
+            }
+            TailEvent::Error(e) => self.last_error = Some(e),
+            TailEvent::Status(path, size) => { self.tail_status.insert(path, size); }
+            TailEvent::BacklogDone => self.backlog_pending = self.backlog_pending.saturating_sub(1),
+        }
+    }
+
+    fn receive_line(&mut self, line: LogLine) {
+        // counted on arrival so the rate reflects the log, paused or not
+        self.recent_lines.push_back(Instant::now());
+        if let Some(out) = self.recorder.as_mut() {
+            let written = serde_json::to_string(&RecordedLine::from_line(&line))
+                .map_err(anyhow::Error::from)
+                .and_then(|json| Ok(writeln!(out, "{}", json)?));
+            if let Err(e) = written {
+                self.recorder = None;
+                self.last_error = Some(format!("Recording stopped: {:#}", e));
+            }
+        }
+        if self.paused {
+            self.held.push(line);
+            // anything past the cap would be evicted on resume anyway
+            cap_scrollback(&mut self.held, self.max_lines);
+        } else {
+            self.push_line(line);
+        }
+    }
+
+    // Lines received during the last second
+    fn lines_per_sec(&mut self) -> usize {
+        let now = Instant::now();
+        while self.recent_lines.front().is_some_and(|t| now.duration_since(*t) > Duration::from_secs(1)) {
+            self.recent_lines.pop_front();
+        }
+        self.recent_lines.len()
+    }
+
+    fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if !self.paused {
+            for line in std::mem::take(&mut self.held) {
+                self.push_line(line);
+            }
+        }
+    }
+
+    fn push_line(&mut self, mut line: LogLine) {
+        if self.group_multiline {
+            group_line(&self.lines, &mut line, &self.color_rules);
+        }
+        if self.session_start.is_none() {
+            self.session_start = line.ts_time;
+        }
+        if let Some(cat) = &line.category {
+            if !self.category_colors.contains_key(cat) {
+                self.category_colors.insert(cat.clone(), color_for_category(cat, &self.category_palette));
+            }
+        }
         // Update COOK detection before moving the line
         let text = line.text.clone();
-        self.update_cook_state(&text);
+        if self.update_cook_state(&text) {
+            self.cook_source = line.source.clone();
+        }
+        self.update_compile_state(&text);
+        self.update_progress(&text);
+        if line.source.is_none() && line.category.as_deref() == Some("LogInit") {
+            self.build_info.update(&text);
+        }
 
+        // autoscroll if pinned to bottom (scroll_from_bottom == 0 keeps the viewport glued
+        // to the end); when scrolled up, grow the offset so the viewport stays put
+        if self.scroll_from_bottom > 0 && self.passes_filters(&line) && !self.extends_repeat(&line) {
+            self.scroll_from_bottom += 1;
+        }
+        self.with_other_pane(|app| {
+            if app.scroll_from_bottom > 0 && app.passes_filters(&line) && !app.extends_repeat(&line) {
+                app.scroll_from_bottom += 1;
+            }
+        });
+        if let Some(limit) = self.cfg.notifications.error_spike {
+            if matches!(line_severity(&line), Some(Verbosity::Fatal | Verbosity::Error)) {
+                self.track_error_spike(limit);
+            }
+        }
+        if line.crash && self.scroll_from_bottom == 0 {
+            self.crash_alert = true;
+        }
+        if self.scroll_from_bottom == 0 && self.backlog_pending == 0 && self.passes_filters(&line)
+            && matches!(line_severity(&line), Some(Verbosity::Fatal | Verbosity::Error)) {
+            self.ring_bell();
+        }
+        // stop_following_on: follow a few more rows past a severe line, then hold the view there
+        if self.scroll_from_bottom == 0 && self.passes_filters(&line) && !self.extends_repeat(&line) {
+            self.stopped_on_error = false; // back at the bottom by some other route
+            match self.error_stop {
+                Some(0) => {
+                    self.error_stop = None;
+                    self.stopped_on_error = true;
+                    self.scroll_from_bottom = 1;
+                }
+                Some(rows) => self.error_stop = Some(rows - 1),
+                None if self.cfg.stop_following_on().is_some_and(|min| line_severity(&line).is_some_and(|v| v <= min)) => {
+                    self.error_stop = Some(ERROR_STOP_ROWS);
+                }
+                None => {}
+            }
+        }
         self.lines.push(line);
-        // cap memory – keep last 20k lines
-        const CAP: usize = 20_000;
-        if self.lines.len() > CAP {
-            let overflow = self.lines.len() - CAP;
-            self.lines.drain(0..overflow);
-            // avoid jumping when scrolled
-            if self.scroll_from_bottom > 0 {
-                self.scroll_from_bottom = self.scroll_from_bottom.saturating_sub(overflow);
+        let overflow = cap_scrollback(&mut self.lines, self.max_lines);
+        self.lines_dropped += overflow;
+        if overflow > 0 {
+            self.search_current = self.search_current.and_then(|i| i.checked_sub(overflow));
+            self.selected_line = self.selected_line.and_then(|i| i.checked_sub(overflow));
+            self.last_seen_index = self.last_seen_index.map(|i| i.saturating_sub(overflow));
+            // evicting from the top leaves the distance from the bottom unchanged
+            self.scroll_from_bottom = self.scroll_from_bottom.min(self.lines.len());
+            let len = self.lines.len();
+            if let Some(pane) = &mut self.split {
+                pane.scroll_from_bottom = pane.scroll_from_bottom.min(len);
+            }
+        }
+    }
+
+    // Shader compilation and Live Coding, tracked independently of the cook
+    fn update_compile_state(&mut self, text: &str) {
+        let lower = text.to_ascii_lowercase();
+        if lower.contains("live coding") || lower.contains("livecoding") {
+            if lower.contains("succeeded") {
+                self.live_coding = None;
+            } else if lower.contains("failed") {
+                self.live_coding = Some(LiveCoding::Failed);
+            } else if lower.contains("starting") || lower.contains("compiling") {
+                self.live_coding = Some(LiveCoding::Compiling);
+            }
+            return;
+        }
+        if let Some(outstanding) = parse_shader_progress_line(text) {
+            if self.shaders_outstanding == 0 {
+                self.shaders_peak = 0; // new burst
+            }
+            self.shaders_outstanding = outstanding;
+            self.shaders_peak = self.shaders_peak.max(outstanding);
+        }
+    }
+
+    // First configured parser that matches wins; numbers may carry thousands separators
+    fn update_progress(&mut self, text: &str) {
+        let number = |m: Option<regex::Match>| m.and_then(|m| m.as_str().replace([',', '.'], "").parse::<u64>().ok());
+        for (i, parser) in self.progress_parsers.iter().enumerate() {
+            let Some(caps) = parser.regex.captures(text) else { continue; };
+            if let (Some(current), Some(total)) = (number(caps.name("current")), number(caps.name("total"))) {
+                self.progress = Some(Progress { parser: i, current, total, updated: Instant::now() });
+                return;
             }
         }
-        // autoscroll if pinned to bottom
-        // (i.e., scroll_from_bottom == 0 keeps the viewport glued to the end)
     }
 
-    fn update_cook_state(&mut self, text: &str) {
+    // Returns true when `text` was a cook line
+    fn update_cook_state(&mut self, text: &str) -> bool {
         let lower = text.to_ascii_lowercase();
         if lower.contains("cook command completed") {
+            if self.cook_active && self.cfg.notifications.cook_complete {
+                self.notify("Cook completed", format!("{}: {} packages cooked", self.target_label(), self.cook_cooked));
+            }
             self.cook_active = false;
+            self.cook_samples.clear();
             // keep last numbers but hide bar
-            return;
+            return true;
         }
         if lower.contains("cook command started") {
             self.cook_active = true;
             self.cook_cooked = 0;
             self.cook_remain = 0;
             self.cook_total = 0;
-            return;
+            self.cook_samples.clear();
+            return true;
         }
         if let Some((cooked, remain, total)) = parse_cook_progress_line(text) {
             self.cook_active = true; // infer active even if start line didn't appear
             self.cook_cooked = cooked;
             self.cook_remain = remain;
             self.cook_total = if total > 0 { total } else { cooked.saturating_add(remain) };
+            self.sample_cook_progress(cooked);
+            return true;
+        }
+        false
+    }
+
+    // At most one sample per second, so a burst (e.g. a loaded backlog) counts as a single point in time
+    fn sample_cook_progress(&mut self, cooked: u64) {
+        let now = Instant::now();
+        match self.cook_samples.back_mut() {
+            Some(last) if now.duration_since(last.0) < Duration::from_secs(1) => *last = (now, cooked),
+            _ => self.cook_samples.push_back((now, cooked)),
+        }
+        while self.cook_samples.front().is_some_and(|s| now.duration_since(s.0) > COOK_ETA_WINDOW) {
+            self.cook_samples.pop_front();
         }
     }
 
+    // Time left at the recent cook rate; None early on (too little history) and while stalled
+    fn cook_eta(&self) -> Option<Duration> {
+        let &(newest_at, newest) = self.cook_samples.back()?;
+        let &(oldest_at, oldest) = self.cook_samples.front()?;
+        // the count hasn't moved for a while: no estimate rather than an ever-growing one
+        let unchanged_since = self.cook_samples.iter().rev().take_while(|s| s.1 == newest).last()?.0;
+        if unchanged_since.elapsed() > COOK_STALL_AFTER { return None; }
+        let span = newest_at.duration_since(oldest_at);
+        if span < Duration::from_secs(5) || newest <= oldest { return None; }
+        let rate = (newest - oldest) as f64 / span.as_secs_f64();
+        let left = Duration::from_secs_f64(self.cook_remain as f64 / rate);
+        Some(left.saturating_sub(newest_at.elapsed()))
+    }
+
+    // Topmost visible line, taken before a filter change so the reading position survives it;
+    // None while following, which a filter change doesn't interrupt
+    fn view_anchor(&self) -> Option<usize> {
+        if self.scroll_from_bottom == 0 { return None; }
+        let filtered = self.filtered_indices();
+        let end = filtered.len().saturating_sub(self.scroll_from_bottom);
+        filtered.get(end.saturating_sub(self.body_rows())).copied()
+    }
+
+    // Put the anchor line back on top, or the first line after it that the new filters still show
+    fn restore_view_anchor(&mut self, anchor: Option<usize>) {
+        let Some(anchor) = anchor else {
+            self.scroll_from_bottom = 0;
+            return;
+        };
+        let filtered = self.filtered_indices();
+        let pos = filtered.partition_point(|&i| i < anchor);
+        self.scroll_from_bottom = filtered.len().saturating_sub(pos + self.body_rows());
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        self.scroll_from_bottom = 0;
+        self.stopped_on_error = false;
+    }
+
     fn scroll_up(&mut self, n: usize) {
         self.scroll_from_bottom = (self.scroll_from_bottom + n).min(self.lines.len());
+        // scrolling by hand takes over from stop_following_on
+        self.error_stop = None;
+        self.stopped_on_error = false;
     }
     fn scroll_down(&mut self, n: usize) {
         self.scroll_from_bottom = self.scroll_from_bottom.saturating_sub(n);
+        self.stopped_on_error = false;
     }
 
-    fn start_tail(&mut self, display_name: String, log_path: PathBuf) -> Result<()> {
-        self.current_name = Some(display_name);
-        self.lines.clear();
-        self.scroll_from_bottom = 0;
-        self.last_error = Some(format!("Watching: {}", log_path.display()));
-        // reset cook status for new target
-        self.cook_active = false;
-        self.cook_cooked = 0;
-        self.cook_remain = 0;
-        self.cook_total = 0;
-
-        // spawn a new tail thread dedicated to this log path
-        let (tx_ev, rx_ev) = mpsc::channel::<AppEvent>();
-        let (tx_cmd, rx_cmd) = mpsc::channel::<Cmd>();
-        spawn_tail(log_path, tx_ev.clone(), rx_cmd);
-        // swap channels into app
-        self.rx = rx_ev;
-        self.tx_cmd = tx_cmd;
-        Ok(())
+    // Walk the minimum verbosity: stricter goes All -> Verbose -> ... -> Fatal, looser goes back
+    fn step_min_verbosity(&mut self, stricter: bool) {
+        let anchor = self.view_anchor();
+        let levels = Verbosity::ALL;
+        self.min_verbosity = match (self.min_verbosity, stricter) {
+            (None, true) => Some(Verbosity::Verbose),
+            (None, false) => None,
+            (Some(v), true) => Some(levels[levels.iter().position(|&l| l == v).unwrap_or(0).saturating_sub(1)]),
+            (Some(Verbosity::Verbose), false) => None,
+            (Some(v), false) => Some(levels[levels.iter().position(|&l| l == v).unwrap_or(0) + 1]),
+        };
+        self.restore_view_anchor(anchor);
+    }
+
+    // F1-F12: put the bound preset's filters in place, or take them off again if they already are
+    fn apply_filter_preset(&mut self, key: u8) {
+        let Some(idx) = self.filter_presets.iter().position(|p| p.key == key) else {
+            self.last_error = Some(format!("F{} has no filter preset (\"filter_presets\" in the config)", key));
+            return;
+        };
+        let active = self.active_preset().is_some_and(|p| p.key == key);
+        let anchor = self.view_anchor();
+        let preset = &self.filter_presets[idx];
+        if active {
+            self.active_categories.clear();
+            self.min_verbosity = None;
+            self.active_regex_filter = None;
+            self.last_error = Some(format!("Preset {} off", preset.name));
+        } else {
+            self.active_categories = preset.categories.clone();
+            self.min_verbosity = preset.min_verbosity;
+            self.active_regex_filter = preset.regex.clone();
+            self.last_error = None;
+        }
+        self.restore_view_anchor(anchor);
+    }
+
+    // The preset whose criteria match the current filters; editing any of them by hand drops the name
+    fn active_preset(&self) -> Option<&FilterPreset> {
+        let regex = self.active_regex_filter.as_ref().map(|re| re.as_str());
+        self.filter_presets.iter().find(|p| {
+            p.categories == self.active_categories
+                && p.min_verbosity == self.min_verbosity
+                && p.regex.as_ref().map(|re| re.as_str()) == regex
+        })
+    }
+
+    fn track_error_spike(&mut self, limit: usize) {
+        let now = Instant::now();
+        let window = Duration::from_secs(self.cfg.notifications.error_window_secs);
+        self.recent_errors.push_back(now);
+        while self.recent_errors.front().is_some_and(|t| now.duration_since(*t) > window) {
+            self.recent_errors.pop_front();
+        }
+        if self.recent_errors.len() > limit {
+            let count = self.recent_errors.len();
+            // start counting afresh so one burst yields one notification
+            self.recent_errors.clear();
+            self.notify("Error spike", format!("{}: {} errors in {}s", self.target_label(), count, window.as_secs()));
+        }
+    }
+
+    // BEL goes to the terminal, which plays, flashes or ignores it as configured there
+    fn ring_bell(&mut self) {
+        let Some(bell) = self.cfg.bell() else { return; };
+        let now = Instant::now();
+        if self.last_bell.is_some_and(|t| now.duration_since(t) < BELL_EVERY) { return; }
+        self.last_bell = Some(now);
+        if bell == Bell::Audible {
+            let mut out = std::io::stdout();
+            let _ = out.write_all(b"\x07").and_then(|_| out.flush());
+        }
+    }
+
+    fn target_label(&self) -> String {
+        self.current_name.clone().unwrap_or_else(|| "ue-tui".to_string())
+    }
+
+    #[cfg(feature = "notifications")]
+    fn notify(&self, summary: &str, body: String) {
+        let summary = summary.to_string();
+        // showing can block on some desktops; never stall the UI for it
+        thread::spawn(move || {
+            let _ = notify_rust::Notification::new().appname("ue-tui").summary(&summary).body(&body).show();
+        });
+    }
+
+    #[cfg(not(feature = "notifications"))]
+    fn notify(&self, _summary: &str, _body: String) {}
+
+    // X: scroll to the first crash marker in the filtered view
+    fn jump_to_crash(&mut self) {
+        self.crash_alert = false;
+        let filtered = self.filtered_indices();
+        match filtered.iter().position(|&i| self.lines[i].crash) {
+            Some(pos) => {
+                let h = self.body_rows();
+                let below = filtered.len() - 1 - pos;
+                // show the marker near the top so the callstack below it is visible
+                self.scroll_from_bottom = below.saturating_sub(h.saturating_sub(3));
+            }
+            None => { self.last_error = Some("No crash marker in the buffer".to_string()); }
+        }
+    }
+
+    // C: drop everything on screen and restart every tail at EOF
+    fn clear_view(&mut self) {
+        for tx in &self.tx_cmds { let _ = tx.send(Cmd::Clear); }
+        self.crash_alert = false;
+        self.error_stop = None;
+        self.stopped_on_error = false;
+        self.session_start = None;
+        self.lines.clear();
+        self.lines_dropped = 0;
+        self.lines_epoch += 1;
+        self.held.clear();
+        self.scroll_from_bottom = 0;
+        self.h_scroll = 0;
+        self.search_current = None;
+        self.selected_line = None;
+        if let Some(pane) = &mut self.split { pane.scroll_from_bottom = 0; }
+        self.last_seen_index = None;
+        self.seen_marker_clear_at = None;
+    }
+
+    // Keeps the "new since you scrolled" marker: set when leaving follow mode, and cleared a few
+    // seconds after following again so the fresh lines stay visible for a moment
+    fn update_seen_marker(&mut self) {
+        const LINGER: Duration = Duration::from_secs(5);
+        if self.scroll_from_bottom > 0 {
+            if self.last_seen_index.is_none() {
+                self.last_seen_index = Some(self.lines.len());
+            }
+            self.seen_marker_clear_at = None;
+        } else if self.last_seen_index.is_some() {
+            match self.seen_marker_clear_at {
+                None => self.seen_marker_clear_at = Some(Instant::now() + LINGER),
+                Some(at) if Instant::now() >= at => {
+                    self.last_seen_index = None;
+                    self.seen_marker_clear_at = None;
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    // Return to project selection menu; the current target keeps tailing
+    fn leave_view(&mut self) {
+        if let Some((key, filters)) = self.current_target_filters() {
+            set_target_filters(&mut self.target_filters, key, filters);
+        }
+        self.current_key = None;
+        self.park_current_target();
+        self.split = None;
+        self.mode = Mode::Select;
+        self.current_name = None;
+        self.current_accent = self.theme.header_project;
+        self.lines.clear();
+        self.lines_dropped = 0;
+        self.lines_epoch += 1;
+        self.scroll_from_bottom = 0;
+        self.last_error = None;
+        self.active_categories.clear();
+        self.active_regex_filter = None;
+        self.active_source_filter = None;
+        self.active_time_filter = None;
+        self.selected_line = None;
+        self.crash_alert = false;
+        self.clear_search();
+    }
+
+    // Per menu entry: None when the filter hides it, otherwise its score and the title chars to highlight
+    fn select_matches(&self) -> Vec<Option<(i64, Vec<usize>)>> {
+        let query = self.select_query.as_deref().unwrap_or_default();
+        self.cfg.target_labels().into_iter().map(|(title, key)| {
+            if query.is_empty() { return Some((0, Vec::new())); }
+            let by_title = fuzzy_match(query, &title);
+            let by_key = fuzzy_match(query, key).map(|(score, _)| (score, Vec::new()));
+            match (by_title, by_key) {
+                (Some(t), Some(k)) => Some(if k.0 > t.0 { k } else { t }),
+                (t, k) => t.or(k),
+            }
+        }).collect()
+    }
+
+    fn selected_is_shown(&self) -> bool {
+        self.select_matches().get(self.selected).is_some_and(|m| m.is_some())
+    }
+
+    // Type into (Some) or backspace (None) the menu filter and move to the best match
+    fn edit_select_query(&mut self, c: Option<char>) {
+        let Some(query) = &mut self.select_query else { return; };
+        match c {
+            Some(c) => query.push(c),
+            None => { query.pop(); }
+        }
+        self.selection_moved_at = Some(Instant::now());
+        let best = self.select_matches().into_iter().enumerate()
+            .filter_map(|(i, m)| Some((m?.0, std::cmp::Reverse(i))))
+            .max();
+        if let Some((_, std::cmp::Reverse(i))) = best {
+            self.selected = i;
+        }
+    }
+
+    // Up/Down in the menu, skipping entries the filter hides
+    fn step_selection(&mut self, down: bool) {
+        let shown: Vec<usize> = self.select_matches().iter().enumerate().filter(|(_, m)| m.is_some()).map(|(i, _)| i).collect();
+        let next = if down {
+            shown.iter().find(|&&i| i > self.selected)
+        } else {
+            shown.iter().rev().find(|&&i| i < self.selected)
+        };
+        if let Some(&i) = next { self.selected = i; }
+        self.selection_moved_at = Some(Instant::now());
+    }
+
+    // Open the given selection-list entries, merged into one view when there are several
+    fn open_targets(&mut self, mut picked: Vec<usize>) -> Result<()> {
+        picked.sort_unstable();
+        let mut targets: Vec<Target> = Vec::new();
+        for idx in picked {
+            if let Some(t) = self.target_at(idx)? { targets.push(t); }
+        }
+        if targets.is_empty() { return Ok(()); }
+        // a merged view keeps a shared accent, otherwise falls back to the project/build default
+        self.current_accent = if targets.iter().all(|t| t.accent == targets[0].accent) {
+            targets[0].accent
+        } else if targets.iter().all(|t| t.is_build) {
+            self.theme.header_build
+        } else {
+            self.theme.header_project
+        };
+        self.current_root = targets[0].root.clone();
+        let name = targets.iter().map(|t| t.name.as_str()).collect::<Vec<_>>().join(" + ");
+        let key = targets.iter().map(|t| t.key.as_str()).collect::<Vec<_>>().join("+");
+        // several targets, or one project with several files, tag each line with where it came from
+        let merged = targets.len() > 1 || targets.iter().any(|t| !t.extra_logs.is_empty() || t.siblings.is_some());
+        // a view restored from the background brings back its own scans (see start_tail)
+        self.sibling_scans = targets.iter_mut().filter_map(|t| t.siblings.take()).collect();
+        let logs = targets.into_iter()
+            .flat_map(|t| {
+                let tag = if merged { Some(t.key.clone()) } else { None };
+                let launched = t.launch.map(|exe| (tag.clone(), LogSource::Launch(exe)));
+                let extra = t.extra_logs.into_iter().map(move |(stem, log)| (Some(format!("{}:{}", t.key, stem)), log));
+                std::iter::once((tag, t.log)).chain(launched).chain(extra)
+            })
+            .collect();
+        self.start_tail(name, Some(key), logs)?;
+        self.checked.clear();
+        self.mode = Mode::View;
+        Ok(())
+    }
+
+    // 1-9: jump straight to the Nth entry of the selection list, from either mode
+    fn open_numbered_target(&mut self, digit: char) -> Result<()> {
+        let n = digit.to_digit(10).unwrap_or(0) as usize;
+        let total = self.cfg.target_count();
+        if n == 0 || n > total {
+            self.last_error = Some(format!("No target {} ({} configured)", n, total));
+            return Ok(());
+        }
+        if self.mode == Mode::View {
+            self.leave_view();
+        }
+        self.selected = n - 1;
+        self.open_targets(vec![n - 1])
+    }
+
+    // Open the first "path(line)" reference of a line in `open_command`, or hand it to $EDITOR
+    fn open_source_ref(&mut self, idx: usize) {
+        let Some((file, line)) = find_source_ref(&self.lines[idx].text) else {
+            self.last_error = Some("No file(line) reference on this line".to_string());
+            return;
+        };
+        let path = match &self.current_root {
+            Some(root) if file.is_relative() => root.join(&file),
+            _ => file,
+        };
+        if !path.exists() {
+            self.last_error = Some(format!("Source file not found: {}", path.display()));
+            return;
+        }
+        let Some(template) = &self.cfg.open_command else {
+            if std::env::var_os("EDITOR").is_some() {
+                self.pending_editor = Some((path, line));
+            } else {
+                self.last_error = Some("Set $EDITOR or \"open_command\" in the config to open files".to_string());
+            }
+            return;
+        };
+        // placeholders are filled per argument so paths with spaces stay one argument
+        let file_arg = path.display().to_string();
+        let mut args = template.split_whitespace()
+            .map(|a| a.replace("{file}", &file_arg).replace("{line}", &line.to_string()));
+        let Some(program) = args.next() else { return; };
+        let spawned = std::process::Command::new(&program).args(args)
+            .stdin(std::process::Stdio::null()).stdout(std::process::Stdio::null()).stderr(std::process::Stdio::null())
+            .spawn();
+        self.last_error = Some(match spawned {
+            Ok(mut child) => {
+                // the editor may outlive us; reap it when it exits so it doesn't linger as a zombie
+                thread::spawn(move || child.wait());
+                format!("Opened {}:{}", file_arg, line)
+            }
+            Err(e) => format!("Cannot run {}: {}", program, e),
+        });
+    }
+
+    // Enter: the given line, else the selected one, the current search match or the bottom visible line
+    fn open_detail(&mut self, line_idx: Option<usize>) {
+        let idx = line_idx.or(self.selected_line).or(self.search_current).or_else(|| {
+            let filtered = self.filtered_indices();
+            filtered.len().checked_sub(self.scroll_from_bottom + 1).map(|i| filtered[i])
+        });
+        match idx {
+            Some(i) => {
+                self.detail = Some(self.lines[i].clone());
+                self.detail_scroll = 0;
+            }
+            None => self.last_error = Some("No line to show".to_string()),
+        }
+    }
+
+    // Y: copy the selected line, or the whole viewport when nothing is selected
+    fn copy_to_clipboard(&mut self) {
+        let text = match self.selected_line {
+            Some(i) => self.lines[i].text.clone(),
+            None => {
+                let filtered = self.filtered_indices();
+                let h = self.body_rows();
+                let end = filtered.len().saturating_sub(self.scroll_from_bottom);
+                let start = end.saturating_sub(h);
+                filtered[start..end].iter().map(|&i| self.lines[i].text.as_str()).collect::<Vec<_>>().join("\n")
+            }
+        };
+        let what = if self.selected_line.is_some() { "line" } else { "visible lines" };
+        self.set_clipboard(text, what);
+    }
+
+    fn set_clipboard(&mut self, text: String, what: &str) {
+        let result = match self.clipboard.as_mut() {
+            Some(cb) => cb.set_text(text),
+            None => arboard::Clipboard::new().and_then(|mut cb| {
+                let r = cb.set_text(text);
+                self.clipboard = Some(cb);
+                r
+            }),
+        };
+        self.last_error = Some(match result {
+            Ok(()) => format!("Copied {} to clipboard", what),
+            Err(e) => format!("Clipboard unavailable: {}", e),
+        });
+    }
+
+    // Shift+O: show the folder of each watched log in the OS file manager
+    fn reveal_log_folder(&mut self) {
+        if self.watching.is_empty() {
+            self.last_error = Some("No log file in this view".to_string());
+            return;
+        }
+        // one file manager window per folder, even when several of its logs are tailed
+        let mut paths: Vec<PathBuf> = Vec::new();
+        for path in &self.watching {
+            if !paths.iter().any(|p| p.parent() == path.parent()) {
+                paths.push(path.clone());
+            }
+        }
+        for path in paths {
+            let mut cmd = if cfg!(windows) {
+                let mut c = std::process::Command::new("explorer");
+                c.arg(format!("/select,{}", path.display()));
+                c
+            } else if cfg!(target_os = "macos") {
+                let mut c = std::process::Command::new("open");
+                c.arg("-R").arg(&path);
+                c
+            } else {
+                let mut c = std::process::Command::new("xdg-open");
+                c.arg(path.parent().unwrap_or(Path::new(".")));
+                c
+            };
+            let spawned = cmd.stdout(std::process::Stdio::null()).stderr(std::process::Stdio::null()).spawn();
+            self.last_error = Some(match spawned {
+                Ok(mut child) => {
+                    // reap it once the opener exits so it doesn't linger as a zombie
+                    thread::spawn(move || child.wait());
+                    format!("Opened the folder of {}", path.display())
+                }
+                Err(e) => format!("Cannot open the file manager: {}", e),
+            });
+        }
+    }
+
+    // Message column text: the whole line as read in raw mode
+    fn rendered_message<'a>(&self, l: &'a LogLine) -> &'a str {
+        if self.show_raw { l.text.as_str() } else { l.display_message() }
+    }
+
+    // Timestamp column text ("[...] "), or None when hidden or the line has no timestamp
+    fn timestamp_part(&self, l: &LogLine) -> Option<String> {
+        if !self.show_timestamp { return None; }
+        if self.show_elapsed {
+            // keep the column aligned for lines whose timestamp doesn't parse
+            let elapsed = match (l.ts_time, self.session_start) {
+                (Some(t), Some(start)) if !l.continuation => format_elapsed(t - start),
+                _ => " ".repeat(10),
+            };
+            return Some(format!("[{}] ", elapsed));
+        }
+        // raw string as the fallback when the timestamp doesn't parse
+        let shown = match (self.cfg.timestamp_pattern(), l.ts_time) {
+            (Some(pattern), Some(t)) if !l.continuation => t.format(pattern).to_string(),
+            _ => l.ts.clone()?,
+        };
+        Some(format!("[{}] ", shown))
+    }
+
+    // Rough heap + inline footprint of the scrollback, for the footer readout
+    fn approx_bytes(&self) -> usize {
+        self.lines.iter().map(|l| {
+            std::mem::size_of::<LogLine>()
+                + l.text.len()
+                + l.message.len()
+                + l.ts.as_ref().map_or(0, |s| s.len())
+                + l.category.as_ref().map_or(0, |s| s.len())
+                + l.source.as_ref().map_or(0, |s| s.len())
+        }).sum()
+    }
+
+    // Log lines that fit in the body: inside the borders, minus the sticky banner row
+    fn body_rows(&self) -> usize {
+        (self.last_body_area.height.saturating_sub(2) as usize).saturating_sub(self.sticky_header as usize)
+    }
+
+    // PageUp/PageDown step: `page_lines` from the config, else a screenful keeping two lines of context
+    fn page_size(&self) -> usize {
+        const OVERLAP: usize = 2;
+        self.cfg.page_lines.unwrap_or_else(|| self.body_rows().saturating_sub(OVERLAP)).max(1)
+    }
+
+    // Cached color of a category; lines parked in the background may not have filled the cache yet
+    fn category_color(&self, cat: &str) -> Color {
+        self.category_colors.get(cat).copied().unwrap_or_else(|| color_for_category(cat, &self.category_palette))
+    }
+
+    // The theme's category style, or the category's palette color
+    fn category_style(&self, cat: &str) -> Style {
+        self.theme.category.unwrap_or_else(|| Style::default().fg(self.category_color(cat)))
+    }
+
+    // Category of the topmost visible line, shown only while it dominates the viewport
+    fn sticky_banner(&self, slice: &[usize]) -> Line<'static> {
+        let top = slice.first().and_then(|&i| self.lines[i].category.clone());
+        let Some(cat) = top else { return Line::raw(""); };
+        let count = slice.iter().filter(|&&i| self.lines[i].category.as_deref() == Some(cat.as_str())).count();
+        if count * 2 <= slice.len() { return Line::raw(""); }
+        Line::from(vec![
+            Span::styled(format!("{}:", cat), self.category_style(&cat).add_modifier(Modifier::UNDERLINED)),
+            Span::styled(format!("  {} of {} visible lines", count, slice.len()), Style::default().fg(Color::DarkGray)),
+        ])
+    }
+
+    // Run `f` with the unfocused pane's filter and scroll swapped in (no-op outside split view)
+    fn with_other_pane<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        let mut pane = self.split.take()?;
+        pane.swap_with(self);
+        let r = f(self);
+        pane.swap_with(self);
+        self.split = Some(pane);
+        Some(r)
+    }
+
+    // Tab: move focus to the other pane of the split view
+    fn switch_pane(&mut self) {
+        let Some(mut pane) = self.split.take() else { return; };
+        pane.swap_with(self);
+        pane.focus_second = !pane.focus_second;
+        self.split = Some(pane);
+    }
+
+    fn open_category_picker(&mut self) {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for l in &self.lines {
+            if let Some(cat) = &l.category {
+                *counts.entry(cat.as_str()).or_default() += 1;
+            }
+        }
+        if counts.is_empty() {
+            self.last_error = Some("No categories seen yet".to_string());
+            return;
+        }
+        let mut rows: Vec<(String, usize)> = counts.into_iter().map(|(c, n)| (c.to_string(), n)).collect();
+        rows.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        // start on the first active category, if any
+        let selected = rows.iter().position(|(c, _)| self.active_categories.contains(c)).unwrap_or(0);
+        self.category_picker = Some(CategoryPicker { rows, selected });
+    }
+
+    // Denylisted by `hidden_categories`, unless revealed or picked in the category filter
+    fn is_hidden(&self, l: &LogLine) -> bool {
+        if self.show_hidden { return false; }
+        l.category.as_ref().is_some_and(|c| self.hidden_categories.contains(c) && !self.active_categories.contains(c))
+    }
+
+    // Counts by category over the whole (unfiltered) buffer
+    fn compute_stats(&self) -> Stats {
+        let mut by_cat: HashMap<&str, StatRow> = HashMap::new();
+        let (mut errors, mut warnings, mut hidden) = (0usize, 0usize, 0usize);
+        let mut levels = LevelCounts::default();
+        for l in &self.lines {
+            if self.is_hidden(l) { hidden += 1; }
+            let cat = l.category.as_deref().unwrap_or("(none)");
+            let row = by_cat.entry(cat).or_insert_with(|| StatRow { category: cat.to_string(), lines: 0, errors: 0, warnings: 0, levels: LevelCounts::default() });
+            row.lines += 1;
+            row.levels.add(l.level);
+            levels.add(l.level);
+            match line_severity(l) {
+                Some(Verbosity::Fatal | Verbosity::Error) => { row.errors += 1; errors += 1; }
+                Some(Verbosity::Warning) => { row.warnings += 1; warnings += 1; }
+                _ => {}
+            }
+        }
+        let mut rows: Vec<StatRow> = by_cat.into_values().collect();
+        rows.sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| a.category.cmp(&b.category)));
+        // merged sources interleave, so the span comes from the extremes rather than the ends
+        let first = self.lines.iter().filter_map(|l| l.ts_time).min();
+        let last = self.lines.iter().filter_map(|l| l.ts_time).max();
+        let stamp = |t: chrono::NaiveDateTime| t.format("%Y-%m-%dT%H:%M:%S%.3f").to_string();
+        Stats {
+            rows,
+            lines: self.lines.len(),
+            errors,
+            warnings,
+            hidden,
+            levels,
+            first: first.map(stamp),
+            last: last.map(stamp),
+            duration_secs: first.zip(last).map(|(a, b)| (b - a).num_seconds()),
+        }
+    }
+
+    // Width of the line-number gutter including its trailing space, 0 when hidden
+    fn gutter_width(&self) -> usize {
+        if !self.show_line_numbers { return 0; }
+        (self.lines_dropped + self.lines.len()).max(1).to_string().len() + 1
+    }
+
+    // Indices into `lines` of the entries that pass the active filters
+    // (with collapsed duplicates, only the last line of each run of repeats)
+    // Cached: only lines that arrived since the last call are tested (see FilterCache)
+    fn filtered_indices(&self) -> Rc<Vec<usize>> {
+        let key = self.filter_key();
+        let mut cache = self.filter_cache.borrow_mut();
+        if cache.key.as_ref() != Some(&key) || cache.epoch != self.lines_epoch {
+            *cache = FilterCache {
+                key: Some(key),
+                epoch: self.lines_epoch,
+                dropped: self.lines_dropped,
+                scanned: self.lines_dropped,
+                indices: Rc::default(),
+                generation: cache.generation + 1,
+            };
+        }
+        let total = self.lines_dropped + self.lines.len();
+        if cache.dropped == self.lines_dropped && cache.scanned == total {
+            return cache.indices.clone();
+        }
+        let shift = self.lines_dropped - cache.dropped;
+        let from = cache.scanned.saturating_sub(self.lines_dropped);
+        let out = Rc::make_mut(&mut cache.indices);
+        if shift > 0 {
+            let evicted = out.partition_point(|&i| i < shift);
+            out.drain(..evicted);
+            for i in out.iter_mut() { *i -= shift; }
+        }
+        for (i, l) in self.lines.iter().enumerate().skip(from) {
+            if !self.passes_filters(l) { continue; }
+            if self.collapse_duplicates {
+                if let Some(last) = out.last_mut() {
+                    if is_repeat(&self.lines[*last], l) {
+                        *last = i;
+                        continue;
+                    }
+                }
+            }
+            out.push(i);
+        }
+        cache.dropped = self.lines_dropped;
+        cache.scanned = total;
+        cache.indices.clone()
+    }
+
+    fn filter_key(&self) -> FilterKey {
+        FilterKey {
+            source: self.active_source_filter.clone(),
+            categories: self.active_categories.clone(),
+            min_verbosity: self.min_verbosity,
+            regex: self.active_regex_filter.as_ref().map(|re| re.as_str().to_string()),
+            time: self.active_time_filter,
+            hidden: if self.show_hidden { HashSet::new() } else { self.hidden_categories.clone() },
+            collapse: self.collapse_duplicates,
+        }
+    }
+
+    // A collapsed repeat of the last visible line adds no row
+    fn extends_repeat(&self, line: &LogLine) -> bool {
+        self.collapse_duplicates
+            && self.lines.iter().rev().find(|l| self.passes_filters(l)).is_some_and(|last| is_repeat(last, line))
+    }
+
+    // Length of the run of repeats ending at `idx`, counting only lines that pass the filters
+    fn repeat_count(&self, idx: usize) -> usize {
+        let l = &self.lines[idx];
+        1 + self.lines[..idx].iter().rev()
+            .filter(|p| self.passes_filters(p))
+            .take_while(|p| is_repeat(p, l))
+            .count()
+    }
+
+    fn passes_filters(&self, l: &LogLine) -> bool {
+        if self.is_hidden(l) { return false; }
+        if let Some(src) = &self.active_source_filter {
+            if l.source.as_deref() != Some(src.as_str()) { return false; }
+        }
+        if !self.active_categories.is_empty() {
+            match &l.category {
+                Some(cat) if self.active_categories.contains(cat) => {}
+                _ => return false,
+            }
+        }
+        if let Some(min) = self.min_verbosity {
+            // lines without an explicit verbosity are plain Log lines
+            if l.level.unwrap_or(Verbosity::Log) > min { return false; }
+        }
+        if let Some(re) = &self.active_regex_filter {
+            let cat_hit = l.category.as_deref().is_some_and(|c| re.is_match(c));
+            if !cat_hit && !re.is_match(&l.text) { return false; }
+        }
+        if let Some((from, to)) = &self.active_time_filter {
+            let Some(t) = &l.ts_time else { return false; };
+            if from.cmp_to(t).is_gt() || to.is_some_and(|to| to.cmp_to(t).is_lt()) { return false; }
+        }
+        true
     }
 }
 
+/* ------------------------------ Search ------------------------------- */
+
 impl App {
+    fn on_input_key(&mut self, kind: KeyEventKind, key: KeyCode, ctrl: bool) {
+        if kind == KeyEventKind::Release { return; }
+        let Some(input) = self.input.as_mut() else { return; };
+        match key {
+            // search options
+            KeyCode::Char('c') if ctrl && input.prompt == Prompt::Search => {
+                self.search_ignore_case = !self.search_ignore_case;
+                self.update_incremental_search();
+            }
+            KeyCode::Char('w') if ctrl && input.prompt == Prompt::Search => {
+                self.search_whole_word = !self.search_whole_word;
+                self.update_incremental_search();
+            }
+            KeyCode::Char('a') if ctrl && input.prompt == Prompt::Search => {
+                self.search_all_categories = !self.search_all_categories;
+            }
+            KeyCode::Up | KeyCode::Down => {
+                let history = match input.prompt {
+                    Prompt::Search => &self.search_history,
+                    Prompt::Regex => &self.filter_history,
+                    Prompt::TimeRange | Prompt::JumpTime => return,
+                };
+                if input.recall(history, key == KeyCode::Up) && input.prompt == Prompt::Search {
+                    self.update_incremental_search();
+                }
+            }
+            KeyCode::Esc => {
+                let prompt = input.prompt;
+                self.input = None;
+                if prompt == Prompt::Search { self.clear_search(); }
+            }
+            KeyCode::Enter => {
+                let input = self.input.take().expect("input line is open");
+                match input.prompt {
+                    Prompt::Search => {
+                        if input.buffer.is_empty() {
+                            self.clear_search();
+                        } else {
+                            push_history(&mut self.search_history, &input.buffer);
+                            self.search_query = Some(SearchQuery::new(input.buffer, self.search_ignore_case, self.search_whole_word));
+                            self.search_below_viewport();
+                        }
+                    }
+                    Prompt::Regex => {
+                        let anchor = self.view_anchor();
+                        if input.buffer.is_empty() {
+                            self.active_regex_filter = None;
+                            self.restore_view_anchor(anchor);
+                        } else {
+                            match regex::Regex::new(&input.buffer) {
+                                Ok(re) => {
+                                    // only filters that compiled are worth recalling
+                                    push_history(&mut self.filter_history, &input.buffer);
+                                    self.active_regex_filter = Some(re);
+                                    self.restore_view_anchor(anchor);
+                                }
+                                Err(e) => {
+                                    // regex errors span several lines; the footer has room for the last one
+                                    let msg = e.to_string();
+                                    self.last_error = Some(format!("Invalid regex: {}", msg.lines().last().unwrap_or_default().trim()));
+                                }
+                            }
+                        }
+                    }
+                    Prompt::TimeRange => {
+                        let anchor = self.view_anchor();
+                        if input.buffer.trim().is_empty() {
+                            self.active_time_filter = None;
+                            self.restore_view_anchor(anchor);
+                        } else if let Some(range) = parse_time_range(&input.buffer) {
+                            self.active_time_filter = Some(range);
+                            self.restore_view_anchor(anchor);
+                        } else {
+                            self.last_error = Some(format!("Invalid time range: {:?} (e.g. 10:30..10:45:30)", input.buffer));
+                        }
+                    }
+                    Prompt::JumpTime => match TimePoint::parse(&input.buffer) {
+                        Some(at) => self.jump_to_time(at),
+                        None if input.buffer.trim().is_empty() => {}
+                        None => self.last_error = Some(format!("Invalid time: {:?} (e.g. 10:30:15)", input.buffer)),
+                    },
+                }
+            }
+            KeyCode::Backspace => {
+                input.buffer.pop();
+                if input.prompt == Prompt::Search { self.update_incremental_search(); }
+            }
+            KeyCode::Char(c) => {
+                input.buffer.push(c);
+                if input.prompt == Prompt::Search { self.update_incremental_search(); }
+            }
+            _ => {}
+        }
+    }
+
+    // Highlight as the user types; the viewport only moves on Enter
+    fn update_incremental_search(&mut self) {
+        let query = self.input.as_ref().map(|i| i.buffer.clone()).unwrap_or_default();
+        self.search_query = if query.is_empty() {
+            None
+        } else {
+            Some(SearchQuery::new(query, self.search_ignore_case, self.search_whole_word))
+        };
+        self.search_current = None;
+    }
+
+    // Put the first visible line at or after `at` on top of the view and select it
+    fn jump_to_time(&mut self, at: TimePoint) {
+        let filtered = self.filtered_indices();
+        let found = filtered.iter().position(|&i| self.lines[i].ts_time.is_some_and(|t| at.cmp_to(&t).is_le()));
+        match found {
+            Some(pos) => self.show_from(&filtered, pos),
+            None => self.last_error = Some(format!("No line at or after {}", at)),
+        }
+    }
+
+    fn clear_search(&mut self) {
+        self.search_query = None;
+        self.search_current = None;
+    }
+
+    // Positions within `filtered` whose line contains the search query
+    fn search_matches(&self, filtered: &[usize]) -> Vec<usize> {
+        let Some(q) = &self.search_query else { return Vec::new(); };
+        filtered.iter().enumerate()
+            .filter(|(_, &i)| q.regex.is_match(&self.lines[i].text))
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    // Enter: focus the first match below the viewport, wrapping to the top
+    fn search_below_viewport(&mut self) {
+        if self.search_across_categories(true) { return; }
+        let filtered = self.filtered_indices();
+        let matches = self.search_matches(&filtered);
+        if matches.is_empty() {
+            self.search_current = None;
+            self.last_error = Some("Search: no matches".to_string());
+            return;
+        }
+        let end = filtered.len().saturating_sub(self.scroll_from_bottom);
+        let pos = matches.iter().copied().find(|&p| p >= end).unwrap_or(matches[0]);
+        self.focus_match(&filtered, pos);
+    }
+
+    // n / N: cycle through all matches in the filtered buffer
+    fn search_step(&mut self, forward: bool) {
+        let Some(cur) = self.search_current else {
+            self.search_below_viewport();
+            return;
+        };
+        if self.search_across_categories(forward) { return; }
+        let filtered = self.filtered_indices();
+        let matches = self.search_matches(&filtered);
+        if matches.is_empty() {
+            self.search_current = None;
+            return;
+        }
+        let pos = if forward {
+            matches.iter().copied().find(|&p| filtered[p] > cur).unwrap_or(matches[0])
+        } else {
+            matches.iter().rev().copied().find(|&p| filtered[p] < cur).unwrap_or(matches[matches.len() - 1])
+        };
+        self.focus_match(&filtered, pos);
+    }
+
+    // "All categories" search while a category filter is set: find the next match as if the filter were off,
+    // then switch the filter to that line's category. False when the ordinary search applies
+    fn search_across_categories(&mut self, forward: bool) -> bool {
+        if !self.search_all_categories || self.active_categories.is_empty() { return false; }
+        // continue after the focused match, or after the bottom visible line
+        let after = self.search_current.or_else(|| {
+            let filtered = self.filtered_indices();
+            let end = filtered.len().saturating_sub(self.scroll_from_bottom);
+            end.checked_sub(1).map(|p| filtered[p])
+        });
+        let picked = std::mem::take(&mut self.active_categories);
+        let unfiltered = self.filtered_indices();
+        let hits: Vec<usize> = self.search_matches(&unfiltered).into_iter().map(|p| unfiltered[p]).collect();
+        let found = if forward {
+            hits.iter().copied().find(|&i| after.is_none_or(|a| i > a)).or(hits.first().copied())
+        } else {
+            hits.iter().rev().copied().find(|&i| after.is_none_or(|a| i < a)).or(hits.last().copied())
+        };
+        let Some(idx) = found else {
+            self.active_categories = picked;
+            self.search_current = None;
+            self.last_error = Some("Search: no matches in any category".to_string());
+            return true;
+        };
+        match self.lines[idx].category.clone() {
+            Some(cat) if picked.contains(&cat) => self.active_categories = picked,
+            Some(cat) => {
+                self.last_error = Some(format!("Search: category filter switched to {}", cat));
+                self.active_categories = HashSet::from([cat]);
+            }
+            // no category to switch to; the filter stays off so the line can be seen
+            None => self.last_error = Some("Search: match has no category, category filter cleared".to_string()),
+        }
+        let filtered = self.filtered_indices();
+        if let Some(pos) = filtered.iter().position(|&i| i == idx) {
+            self.focus_match(&filtered, pos);
+        }
+        true
+    }
+
+    // Scroll so that filtered position `pos` sits in the middle of the body
+    fn focus_match(&mut self, filtered: &[usize], pos: usize) {
+        self.search_current = Some(filtered[pos]);
+        let h = self.body_rows();
+        let below = filtered.len() - 1 - pos;
+        self.scroll_from_bottom = below.saturating_sub(h / 2);
+    }
+
+    // Resolve the selection-list entry at `idx` to a tailable log
+    fn target_at(&self, idx: usize) -> Result<Option<Target>> {
+        let pcount = self.cfg.projects.len();
+        if idx < pcount {
+            let project = &self.cfg.projects[idx];
+            let path = match &project.log_path {
+                Some(p) => p.clone(),
+                None => log_path_from_uproject(&project.uproject)?,
+            };
+            let mut extra = project.extra_logs.clone();
+            if project.all_logs {
+                extra.extend(sibling_logs(&path));
+            }
+            extra.sort();
+            extra.dedup();
+            extra.retain(|p| *p != path);
+            let siblings = project.all_logs.then(|| SiblingScan {
+                main: path.clone(),
+                key: project.key.clone(),
+                robust: project.robust_polling,
+                known: std::iter::once(path.clone()).chain(extra.iter().cloned()).collect(),
+            });
+            Ok(Some(Target {
+                key: project.key.clone(),
+                name: project.name_or_key(),
+                log: LogSource::File { path, robust: project.robust_polling },
+                is_build: false,
+                accent: accent_color(project.color.as_deref(), self.theme.header_project),
+                root: project.uproject.parent().map(Path::to_path_buf),
+                launch: None,
+                extra_logs: extra.into_iter().map(|p| {
+                    let stem = p.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+                    (stem, LogSource::File { path: p, robust: project.robust_polling })
+                }).collect(),
+                siblings,
+            }))
+        } else if let Some(build) = self.cfg.builds.get(idx - pcount) {
+            Ok(Some(Target {
+                key: build.key.clone(),
+                name: build.name_or_key(),
+                log: LogSource::File {
+                    path: match &build.log_path {
+                        Some(p) => p.clone(),
+                        None => log_path_from_exe(&build.exe)?,
+                    },
+                    robust: build.robust_polling,
+                },
+                is_build: true,
+                accent: accent_color(build.color.as_deref(), self.theme.header_build),
+                root: build.exe.parent().map(Path::to_path_buf),
+                launch: build.launch.then(|| build.exe.clone()),
+                extra_logs: Vec::new(),
+                siblings: None,
+            }))
+        } else if let Some(remote) = self.cfg.remotes.get(idx - pcount - self.cfg.builds.len()) {
+            Ok(Some(Target {
+                key: remote.key.clone(),
+                name: remote.name_or_key(),
+                log: LogSource::Command(remote.command.clone()),
+                is_build: false,
+                accent: accent_color(remote.color.as_deref(), self.theme.header_remote),
+                root: None,
+                launch: None,
+                extra_logs: Vec::new(),
+                siblings: None,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Tail one log, or several merged into one view when each carries a source tag
+    fn start_tail(&mut self, display_name: String, key: Option<String>, logs: Vec<(Option<String>, LogSource)>) -> Result<()> {
+        // reset cook status for new target
+        self.cook_active = false;
+        self.cook_cooked = 0;
+        self.cook_remain = 0;
+        self.cook_total = 0;
+        self.cook_samples.clear();
+        self.cook_source = None;
+        self.disk_free = None;
+        self.last_disk_check = None;
+        self.shaders_outstanding = 0;
+        self.shaders_peak = 0;
+        self.live_coding = None;
+        self.progress = None;
+        // merged views mix several logs; only a single file is summarised
+        self.build_info = match logs.as_slice() {
+            [(_, LogSource::File { path, .. })] => BuildInfo::from_file_head(path),
+            _ => BuildInfo::default(),
+        };
+        self.session_start = None;
+        self.backlog_pending = 0;
+        self.last_seen_index = None;
+        self.seen_marker_clear_at = None;
+        self.recent_lines.clear();
+        self.tail_status.clear();
+        if let Some(key) = &key {
+            let saved = self.target_filters.get(key).cloned().unwrap_or_default();
+            self.apply_target_filters(&saved);
+        }
+        self.current_key = key;
+        // whatever is on screen now is replaced below; its threads must not outlive it
+        for tx in &self.tx_cmds { let _ = tx.send(Cmd::Stop); }
+        let watching = logs.iter().map(|(_, log)| source_label(log)).collect::<Vec<_>>().join(", ");
+        self.watching = logs.iter().filter_map(|(_, log)| match log {
+            LogSource::File { path, .. } | LogSource::Replay { path, .. } => Some(path.clone()),
+            _ => None,
+        }).collect();
+
+        // Returning to a target seen earlier: resume its buffer and tail thread
+        if let Some(state) = self.background.remove(&display_name) {
+            self.current_name = Some(display_name);
+            self.lines = state.lines;
+            self.lines_dropped = state.lines_dropped;
+            self.sibling_scans = state.sibling_scans;
+            self.tx_events = state.tx_events;
+            self.queued = state.queued;
+            self.lines_epoch += 1;
+            self.session_start = self.lines.iter().find_map(|l| l.ts_time);
+            self.scroll_from_bottom = state.scroll_from_bottom;
+            self.tail_status = state.tail_status;
+            self.rx = state.rx;
+            self.tx_cmds = state.tx_cmds;
+            self.last_error = Some(format!("Watching: {} (restored {} lines)", watching, self.lines.len()));
+            return Ok(());
+        }
+
+        self.current_name = Some(display_name);
+        self.lines.clear();
+        self.lines_dropped = 0;
+        self.lines_epoch += 1;
+        self.scroll_from_bottom = 0;
+        self.last_error = Some(format!("Watching: {}", watching));
+        // a conventional path that can't be right would otherwise just look like a quiet log
+        if let Some(warning) = logs.iter().find_map(|(_, log)| match log {
+            LogSource::File { path, .. } => log_path_warning(path),
+            _ => None,
+        }) {
+            self.last_error = Some(warning);
+        }
+        if self.tail_from_start {
+            self.backlog_pending = logs.len();
+        }
+
+        // spawn a tail thread per log path, all feeding the same event channel
+        let (tx_ev, rx_ev) = mpsc::channel::<TailEvent>();
+        self.queued = Arc::default();
+        let mut tx_cmds = Vec::with_capacity(logs.len());
+        for (source, log) in logs {
+            let (tx_cmd, rx_cmd) = mpsc::channel::<Cmd>();
+            let opts = TailOptions { source, color_rules: self.color_rules.clone(), from_start: self.tail_from_start, poll: self.cfg.poll_interval(), robust: false, queued: Some(self.queued.clone()) };
+            let owns_child = matches!(log, LogSource::Command(_) | LogSource::Launch(_));
+            let handle = spawn_source(log, opts, tx_ev.clone(), rx_cmd);
+            if owns_child {
+                self.child_threads.retain(|h| !h.is_finished());
+                self.child_threads.push(handle);
+            }
+            tx_cmds.push(tx_cmd);
+        }
+        // swap channels into app
+        self.rx = rx_ev;
+        self.tx_cmds = tx_cmds;
+        self.tx_events = Some(tx_ev);
+        Ok(())
+    }
+
+    fn save_state(&self) {
+        let state = UiState {
+            selected: self.selected,
+            show_timestamp: self.show_timestamp,
+            wrap_lines: self.wrap_lines,
+            show_line_numbers: self.show_line_numbers,
+            show_header: self.show_header,
+            show_footer: self.show_footer,
+            search_history: self.search_history.clone(),
+            filter_history: self.filter_history.clone(),
+            path_style: self.path_style,
+            targets: {
+                let mut targets = self.target_filters.clone();
+                if let Some((key, filters)) = self.current_target_filters() {
+                    set_target_filters(&mut targets, key, filters);
+                }
+                targets
+            },
+        };
+        if let Ok(json) = serde_json::to_vec_pretty(&state) {
+            let _ = fs::write(&self.state_path, json);
+        }
+    }
+
+    // --replay: show a recorded session instead of a live log
+    fn open_replay(&mut self, path: PathBuf, fast: bool) -> Result<()> {
+        let name = format!("Replay: {}", path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default());
+        self.current_accent = self.theme.header_project;
+        self.current_root = None;
+        self.start_tail(name, None, vec![(None, LogSource::Replay { path, fast })])?;
+        self.mode = Mode::View;
+        Ok(())
+    }
+
+    // Tail a log given on the command line, skipping the selection menu
+    fn open_log_file(&mut self, path: PathBuf) -> Result<()> {
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| path.display().to_string());
+        let exists = path.exists();
+        let shown = path.display().to_string();
+        self.current_accent = self.theme.header_project;
+        self.current_root = None;
+        self.start_tail(name, None, vec![(None, LogSource::File { path, robust: false })])?;
+        if !exists {
+            self.last_error = Some(format!("Waiting for file: {}", shown));
+        }
+        self.mode = Mode::View;
+        Ok(())
+    }
+
+    // Write the filtered (and, if searching, matching) lines to a timestamped file in the cwd
+    fn export_view(&mut self, ansi: bool) {
+        let filtered = self.filtered_indices();
+        let indices: Vec<usize> = if self.search_query.is_some() {
+            self.search_matches(&filtered).into_iter().map(|p| filtered[p]).collect()
+        } else {
+            filtered.to_vec()
+        };
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let path = PathBuf::from(format!("unreal-tui-export-{}.log", stamp));
+        let result = (|| -> Result<()> {
+            let mut out = std::io::BufWriter::new(File::create(&path)?);
+            for &i in &indices {
+                let l = &self.lines[i];
+                if ansi {
+                    writeln!(out, "{}{}\x1b[0m", ansi_fg(l.color), l.text)?;
+                } else {
+                    writeln!(out, "{}", l.text)?;
+                }
+            }
+            out.flush()?;
+            Ok(())
+        })();
+        self.last_error = Some(match result {
+            Ok(()) => format!("Exported {} lines to {}", indices.len(), path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    // E / Shift+E in the stats popup: the snapshot on screen as JSON or CSV, next to view exports
+    fn export_stats(&mut self, csv: bool) {
+        let Some(stats) = &self.stats else { return; };
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let path = PathBuf::from(format!("unreal-tui-stats-{}.{}", stamp, if csv { "csv" } else { "json" }));
+        let text = if csv {
+            Ok(stats_csv(stats))
+        } else {
+            serde_json::to_string_pretty(stats).map_err(anyhow::Error::from)
+        };
+        let result = text.and_then(|text| Ok(fs::write(&path, text)?));
+        self.last_error = Some(match result {
+            Ok(()) => format!("Saved stats to {}", path.display()),
+            Err(e) => format!("Stats export failed: {}", e),
+        });
+    }
+
+    // The on-screen target's filters as they would be saved
+    fn current_target_filters(&self) -> Option<(String, TargetFilters)> {
+        let key = self.current_key.clone()?;
+        let mut categories: Vec<String> = self.active_categories.iter().cloned().collect();
+        categories.sort_unstable();
+        Some((key, TargetFilters {
+            categories,
+            min_verbosity: self.min_verbosity.map(|v| v.as_str().to_string()),
+            regex: self.active_regex_filter.as_ref().map(|re| re.as_str().to_string()),
+            collapse_duplicates: self.collapse_duplicates,
+            show_hidden: self.show_hidden,
+        }))
+    }
+
+    // Saved entries come from an older run or a hand edit, so anything that no longer parses is dropped
+    fn apply_target_filters(&mut self, saved: &TargetFilters) {
+        self.active_categories = saved.categories.iter().cloned().collect();
+        self.min_verbosity = saved.min_verbosity.as_deref().and_then(Verbosity::parse);
+        self.active_regex_filter = saved.regex.as_deref().and_then(|re| regex::Regex::new(re).ok());
+        self.collapse_duplicates = saved.collapse_duplicates;
+        self.show_hidden = saved.show_hidden;
+    }
+
+    // Ctrl+F: reset the view's filters and stop remembering them for this target
+    fn forget_target_filters(&mut self) {
+        let anchor = self.view_anchor();
+        self.apply_target_filters(&TargetFilters::default());
+        self.active_source_filter = None;
+        self.active_time_filter = None;
+        self.restore_view_anchor(anchor);
+        self.last_error = Some(match &self.current_key {
+            Some(key) if self.target_filters.remove(key).is_some() => format!("Forgot the saved filters of {}", key),
+            Some(_) => "Filters reset; none were saved for this target".to_string(),
+            None => "Filters reset; only configured targets remember theirs".to_string(),
+        });
+    }
+
+    // Move the on-screen target into `background` and detach the app from its channels
+    fn park_current_target(&mut self) {
+        let Some(name) = self.current_name.clone() else { return; };
+        if self.paused { self.toggle_pause(); }
+        let (rx, tx_cmds) = idle_channels();
+        let state = TargetState {
+            lines: std::mem::take(&mut self.lines),
+            lines_dropped: std::mem::take(&mut self.lines_dropped),
+            sibling_scans: std::mem::take(&mut self.sibling_scans),
+            tx_events: self.tx_events.take(),
+            queued: std::mem::take(&mut self.queued),
+            scroll_from_bottom: self.scroll_from_bottom,
+            tail_status: std::mem::take(&mut self.tail_status),
+            rx: std::mem::replace(&mut self.rx, rx),
+            tx_cmds: std::mem::replace(&mut self.tx_cmds, tx_cmds),
+        };
+        self.lines_epoch += 1;
+        self.background.insert(name, state);
+    }
+
+    // Ask every tail thread, on screen or parked, to exit
+    fn stop_all_tails(&mut self) {
+        let parked = self.background.values().flat_map(|state| state.tx_cmds.iter());
+        for tx in self.tx_cmds.iter().chain(parked) {
+            let _ = tx.send(Cmd::Stop);
+        }
+        // commands and launched programs are killed by their thread; wait for that before exiting
+        for handle in self.child_threads.drain(..) {
+            let _ = handle.join();
+        }
+    }
+
+    fn drain_background(&mut self) {
+        const MAX_EVENTS_PER_TARGET: usize = 1000;
+        let cap = self.max_lines;
+        for state in self.background.values_mut() {
+            for _ in 0..MAX_EVENTS_PER_TARGET {
+                match state.rx.try_recv() {
+                    Ok(TailEvent::Line(mut l)) => {
+                        state.queued.fetch_sub(1, Ordering::Relaxed);
+                        if self.group_multiline {
+                            group_line(&state.lines, &mut l, &self.color_rules);
+                        }
+                        state.lines.push(l);
+                        state.lines_dropped += cap_scrollback(&mut state.lines, cap);
+                        if state.scroll_from_bottom > 0 {
+                            state.scroll_from_bottom = (state.scroll_from_bottom + 1).min(state.lines.len());
+                        }
+                    }
+                    Ok(TailEvent::Status(path, size)) => { state.tail_status.insert(path, size); }
+                    Ok(_) => {}
+                    Err(_) => break,
+                }
+            }
+        }
+    }
+}
+
+impl App {
+    // Sample free space next to the logs every 10 seconds while a cook runs
+    fn maybe_check_disk(&mut self) {
+        if !self.cook_active || self.mode != Mode::View { return; }
+        if self.last_disk_check.is_some_and(|t| t.elapsed() < Duration::from_secs(10)) { return; }
+        self.last_disk_check = Some(Instant::now());
+        let logs: Vec<&PathBuf> = self.tail_status.keys().collect();
+        self.disk_free = free_disk_space(&logs);
+    }
+
+    // Free bytes when below the configured threshold
+    fn low_disk(&self) -> Option<u64> {
+        let threshold = self.cfg.low_disk_gb.unwrap_or(10.0).max(0.0) * 1024.0 * 1024.0 * 1024.0;
+        self.disk_free.filter(|&free| (free as f64) < threshold)
+    }
+
+    // `all_logs`: add logs that appeared next to an open project's log since the last look, read from the start
+    fn tail_new_sibling_logs(&mut self) {
+        let Some(tx_ev) = self.tx_events.clone() else { return; };
+        let mut added = Vec::new();
+        for scan in &mut self.sibling_scans {
+            for path in sibling_logs(&scan.main) {
+                if scan.known.contains(&path) { continue; }
+                scan.known.push(path.clone());
+                let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+                added.push((format!("{}:{}", scan.key, stem), path, scan.robust));
+            }
+        }
+        for (source, path, robust) in added {
+            let (tx_cmd, rx_cmd) = mpsc::channel::<Cmd>();
+            let opts = TailOptions { source: Some(source), color_rules: self.color_rules.clone(), from_start: true, poll: self.cfg.poll_interval(), robust: false, queued: Some(self.queued.clone()) };
+            spawn_source(LogSource::File { path: path.clone(), robust }, opts, tx_ev.clone(), rx_cmd);
+            self.tx_cmds.push(tx_cmd);
+            self.backlog_pending += 1;
+            self.last_error = Some(format!("Also tailing {}", source_label(&LogSource::File { path: path.clone(), robust })));
+            self.watching.push(path);
+        }
+    }
+
     fn maybe_refresh_discovered(&mut self) {
-        // Only refresh in selection menu, every ~3 seconds
-        if self.mode != Mode::Select { return; }
+        // Only refresh in selection menu (or look for new `all_logs` files in a view), every ~3 seconds
         let now = Instant::now();
         if now.duration_since(self.last_discovery_check) < Duration::from_secs(3) {
             return;
         }
+        if self.mode == Mode::View && !self.sibling_scans.is_empty() {
+            self.last_discovery_check = now;
+            self.tail_new_sibling_logs();
+            return;
+        }
+        if self.mode != Mode::Select { return; }
         self.last_discovery_check = now;
         let before = self.cfg.projects.len();
         merge_discovered_into_config(&mut self.cfg);
         // Keep selection index in bounds
-        let total = self.cfg.projects.len() + self.cfg.builds.len();
+        let total = self.cfg.target_count();
         if total == 0 {
             self.selected = 0;
         } else if self.selected >= total {
@@ -601,110 +4012,128 @@ impl App {
         if self.cfg.projects.len() > before && before == 0 {
             self.last_error = Some("Editor aperto rilevato automaticamente".to_string());
         }
+        // A freshly launched editor is probably the one wanted, unless the user is browsing the list
+        const BROWSING: Duration = Duration::from_secs(10);
+        let browsing = self.selection_moved_at.is_some_and(|t| now.duration_since(t) < BROWSING);
+        if self.cfg.projects.len() > before && !browsing {
+            let newest = self.cfg.projects.iter().enumerate().skip(before)
+                .filter_map(|(i, p)| Some((p.started?, i)))
+                .max();
+            if let Some((_, idx)) = newest {
+                self.selected = idx;
+                self.last_error = Some(format!("Selected newly opened editor: {}", self.cfg.projects[idx].name_or_key()));
+            }
+        }
+    }
+
+    // Re-read the config files when one changes, appears or goes away; a broken edit keeps the old config active
+    fn maybe_reload_config(&mut self) {
+        if self.mode != Mode::Select { return; }
+        let now = Instant::now();
+        if now.duration_since(self.last_config_check) < Duration::from_secs(1) {
+            return;
+        }
+        self.last_config_check = now;
+        let paths = config_files();
+        let mtimes = config_mtimes(&paths);
+        if paths == self.config_paths && mtimes == self.config_mtimes { return; }
+        self.config_paths = paths;
+        self.config_mtimes = mtimes;
+
+        let loaded = load_config_files(&self.config_paths).and_then(|cfg| {
+            let rules = compile_color_rules(&cfg.colors).context("Invalid \"colors\" rule")?;
+            let highlights = compile_highlights(&cfg.highlights).context("Invalid \"highlights\" entry")?;
+            let presets = compile_filter_presets(&cfg.filter_presets).context("Invalid \"filter_presets\" entry")?;
+            let progress = compile_progress_parsers(&cfg.progress).context("Invalid \"progress\" entry")?;
+            Ok((cfg, rules, highlights, presets, progress))
+        });
+        match loaded {
+            Ok((mut cfg, rules, highlights, presets, progress)) => {
+                merge_discovered_into_config(&mut cfg);
+                self.max_lines = cfg.max_lines.unwrap_or(DEFAULT_MAX_LINES).max(1);
+                self.color_rules = Arc::new(rules);
+                self.highlights = highlights;
+                self.filter_presets = presets;
+                self.progress_parsers = progress;
+                self.progress = None; // its parser index may be gone
+                self.category_palette = cfg.category_palette();
+                self.category_colors.clear();
+                self.theme = Theme::new(&cfg.theme, self.no_color.then_some("monochrome")).unwrap_or_else(|_| self.theme.clone());
+                self.hidden_categories = cfg.hidden_categories.iter().cloned().collect();
+                self.cfg = cfg;
+                self.config_generation += 1;
+                self.checked.clear();
+                let total = self.cfg.target_count();
+                self.selected = self.selected.min(total.saturating_sub(1));
+                self.last_error = Some(match self.config_paths.as_slice() {
+                    [] => "Config file removed; showing discovered editors only".to_string(),
+                    paths => format!("Reloaded {}", join_paths(paths)),
+                });
+            }
+            Err(e) => {
+                self.last_error = Some(format!("Config not reloaded: {:#}", e));
+            }
+        }
     }
 }
 
 /* ---------------------------- Tail threads --------------------------- */
 
-fn spawn_idle_tail(tx: mpsc::Sender<AppEvent>, rx_cmd: mpsc::Receiver<Cmd>) {
-    thread::spawn(move || {
-        let _ = rx_cmd.recv(); // block forever until replaced by a real tail
-        let _ = tx.send(AppEvent::Tick);
-    });
+// Channels backed by an idle tail thread, used while no target is on screen
+fn idle_channels() -> (mpsc::Receiver<TailEvent>, Vec<mpsc::Sender<Cmd>>) {
+    let (tx_ev, rx) = mpsc::channel::<TailEvent>();
+    let (tx_cmd, rx_cmd) = mpsc::channel::<Cmd>();
+    spawn_idle_tail(tx_ev, rx_cmd);
+    (rx, vec![tx_cmd])
 }
 
-fn spawn_tail(path: PathBuf, tx: mpsc::Sender<AppEvent>, rx_cmd: mpsc::Receiver<Cmd>) {
+fn spawn_idle_tail(tx: mpsc::Sender<TailEvent>, rx_cmd: mpsc::Receiver<Cmd>) {
     thread::spawn(move || {
-        // Start from EOF; we don't want to flood with old lines.
-        let mut offset: u64 = match fs::metadata(&path) { Ok(m) => m.len(), Err(_) => 0 };
-        let mut carry = String::new();
-        // Track file identity to handle recreation/rotation even when sizes match
-        let mut last_created: Option<std::time::SystemTime> = None;
-        let mut last_modified: Option<std::time::SystemTime> = None;
-
-        loop {
-            // Commands (non-blocking)
-            if let Ok(Cmd::Clear) = rx_cmd.try_recv() {
-                if let Ok(len) = fs::metadata(&path).map(|m| m.len()) {
-                    offset = len;
-                }
-                carry.clear();
-            }
-
-            // Try to read new data
-            match fs::metadata(&path) {
-                Ok(meta) => {
-                    // Detect recreation/rotation:
-                    let created = meta.created().ok();
-                    let modified = meta.modified().ok();
-
-                    // If creation time changed (or appears after being None), it's a new file
-                    let recreated = match (last_created, created) {
-                        (Some(prev), Some(cur)) => cur != prev,
-                        (None, Some(_)) => false, // first time we see it; don't jump to beginning unless len decreased
-                        _ => false,
-                    };
-                    // If modified time goes backwards (or drastically changes while len == offset), treat as rotation
-                    let mod_time_backwards = match (last_modified, modified) {
-                        (Some(prev), Some(cur)) => cur < prev,
-                        _ => false,
-                    };
-
-                    if recreated || mod_time_backwards {
-                        offset = 0;
-                        carry.clear();
-                    }
-
-                    // Update identity trackers
-                    last_created = created.or(last_created);
-                    last_modified = modified.or(last_modified);
-
-                    let len = meta.len();
-                    if offset > len { offset = 0; } // rotated or truncated
-
-                    if len > offset {
-                        let to_read = (len - offset) as usize;
-                        if let Ok(mut f) = File::open(&path) {
-                            if f.seek(SeekFrom::Start(offset)).is_ok() {
-                                let mut buf = vec![0u8; to_read];
-                                match f.read(&mut buf) {
-                                    Ok(n) if n > 0 => {
-                                        offset += n as u64;
-                                        let chunk = String::from_utf8_lossy(&buf[..n]);
-                                        carry.push_str(&chunk);
-
-                                        // Split on '\n', keep trailing partial in 'carry'
-                                        let mut parts = carry.split('\n').map(|s| s.to_string()).collect::<Vec<_>>();
-                                        carry = if chunk.ends_with('\n') { String::new() } else { parts.pop().unwrap_or_default() };
-
-                                        for mut line in parts {
-                                            if line.ends_with('\r') { let _ = line.pop(); }
-                                            if line.trim().is_empty() { continue; }
-                                            let color = classify_line(&line);
-                                            let (ts, category, message) = parse_log_components(&line);
-                                            let _ = tx.send(AppEvent::Line(LogLine { text: line, color, ts, category, message }));
-                                        }
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
-                    }
-                }
-                Err(_) => {
-                    // file not found yet – clear identity and wait
-                    last_created = None;
-                    last_modified = None;
-                }
-            }
+        // keeps the channel open, sending nothing, until replaced by a real tail
+        let _ = rx_cmd.recv();
+        drop(tx);
+    });
+}
 
-            thread::sleep(Duration::from_millis(150));
+// Spans for `text`, which starts `first` chars into a message, colored by its ANSI runs
+fn ansi_spans(text: &str, first: usize, runs: &[(usize, LineColor)], base: Style) -> Vec<Span<'static>> {
+    if runs.is_empty() {
+        return vec![Span::styled(text.to_string(), base)];
+    }
+    let color_at = |pos: usize| match runs.iter().take_while(|(at, _)| *at <= pos).last() {
+        Some(&(_, c)) if c != LineColor::Reset => base.fg(tui_color(c)),
+        _ => base,
+    };
+    let mut out: Vec<Span<'static>> = Vec::new();
+    let mut piece = String::new();
+    let mut piece_color = color_at(first);
+    for (i, ch) in text.chars().enumerate() {
+        let c = color_at(first + i);
+        if c != piece_color && !piece.is_empty() {
+            out.push(Span::styled(std::mem::take(&mut piece), piece_color));
         }
-    });
+        piece_color = c;
+        piece.push(ch);
+    }
+    out.push(Span::styled(piece, piece_color));
+    out
 }
 
 /* ------------------------------ Helpers ------------------------------ */
 
+// less/vim style readout of where the viewport sits: ALL, TOP, BOT or the top row's percentage
+fn scroll_position(total: usize, from_bottom: usize, rows: usize) -> String {
+    if total <= rows { return "ALL".to_string(); }
+    let max_scroll = total - rows;
+    if from_bottom == 0 {
+        "BOT".to_string()
+    } else if from_bottom >= max_scroll {
+        "TOP".to_string()
+    } else {
+        format!("{}%", (max_scroll - from_bottom) * 100 / max_scroll)
+    }
+}
+
 fn slugify(s: &str) -> String {
     let mut out = String::new();
     let mut last_dash = false;
@@ -712,12 +4141,10 @@ fn slugify(s: &str) -> String {
         let lower = ch.to_ascii_lowercase();
         if lower.is_ascii_alphanumeric() {
             out.push(lower);
-            last_dash = false;
-        } else {
-            if !last_dash && !out.is_empty() {
-                out.push('-');
-                last_dash = true;
-            }
+            last_dash = false;
+        } else if !last_dash && !out.is_empty() {
+            out.push('-');
+            last_dash = true;
         }
     }
     if out.ends_with('-') { out.pop(); }
@@ -730,12 +4157,13 @@ fn discover_open_editors() -> Vec<Project> {
     sys.refresh_processes();
     let mut results: Vec<Project> = Vec::new();
 
-    // Known editor exe name patterns
-    let patterns = ["unrealeditor.exe", "ue4editor.exe", "ue5editor.exe"]; // case-insensitive
+    // Known editor base names; Windows adds ".exe", Linux/macOS binaries have no extension
+    let patterns = ["unrealeditor", "ue4editor", "ue5editor"]; // case-insensitive
 
-    for (_pid, proc_) in sys.processes() {
+    for proc_ in sys.processes().values() {
         let exe_name = proc_.name().to_ascii_lowercase();
-        if !patterns.iter().any(|p| exe_name.contains(p)) {
+        let base = exe_name.strip_suffix(".exe").unwrap_or(&exe_name);
+        if !patterns.iter().any(|p| base.contains(p)) {
             continue;
         }
         let cmd = proc_.cmd();
@@ -758,26 +4186,41 @@ fn discover_open_editors() -> Vec<Project> {
         if let Some(up) = uproject_path {
             let name = up.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_else(|| "Project".to_string());
             let key = slugify(&name);
-            results.push(Project { key, name, uproject: up, discovered: true });
+            results.push(Project { key, name, uproject: up, log_path: None, extra_logs: Vec::new(), all_logs: false, color: None, robust_polling: false, discovered: true, started: Some(proc_.start_time()), running: true });
         }
     }
     results
 }
 
+// Default filters need no entry, which keeps the state file to targets that were actually customised
+fn set_target_filters(targets: &mut BTreeMap<String, TargetFilters>, key: String, filters: TargetFilters) {
+    if filters == TargetFilters::default() {
+        targets.remove(&key);
+    } else {
+        targets.insert(key, filters);
+    }
+}
+
+// Canonical lowercase .uproject path, so config entries and editor command lines compare equal
+fn uproject_id(path: &Path) -> String {
+    fs::canonicalize(path)
+        .map(|c| c.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_else(|_| path.to_string_lossy().to_ascii_lowercase())
+}
+
 fn merge_discovered_into_config(cfg: &mut Config) {
-    // Collect existing by canonical lowercase uproject path and by key
+    let discovered = discover_open_editors();
+    let live: HashSet<String> = discovered.iter().map(|p| uproject_id(&p.uproject)).collect();
+    // Collect existing by canonical lowercase uproject path and by key; mark the ones open right now
     let mut existing_paths: HashMap<String, ()> = HashMap::new();
     let mut existing_keys: HashMap<String, ()> = HashMap::new();
-    for p in &cfg.projects {
+    for p in &mut cfg.projects {
         let key = p.key.to_ascii_lowercase();
         existing_keys.insert(key, ());
-        if let Ok(canon) = fs::canonicalize(&p.uproject) {
-            existing_paths.insert(canon.to_string_lossy().to_ascii_lowercase(), ());
-        } else {
-            existing_paths.insert(p.uproject.to_string_lossy().to_ascii_lowercase(), ());
-        }
+        let path_lc = uproject_id(&p.uproject);
+        p.running = live.contains(&path_lc);
+        existing_paths.insert(path_lc, ());
     }
-    let discovered = discover_open_editors();
     for mut p in discovered {
         // Ensure correct key/name for discovered
         if p.name.is_empty() {
@@ -787,9 +4230,7 @@ fn merge_discovered_into_config(cfg: &mut Config) {
             }
         }
         // Deduplicate using path, then key
-        let path_lc = fs::canonicalize(&p.uproject)
-            .map(|c| c.to_string_lossy().to_ascii_lowercase())
-            .unwrap_or_else(|_| p.uproject.to_string_lossy().to_ascii_lowercase());
+        let path_lc = uproject_id(&p.uproject);
         if existing_paths.contains_key(&path_lc) { continue; }
         if existing_keys.contains_key(&p.key.to_ascii_lowercase()) { continue; }
         cfg.projects.push(p.clone());
@@ -798,130 +4239,966 @@ fn merge_discovered_into_config(cfg: &mut Config) {
     }
 }
 
-fn load_config() -> Result<Config> {
-    // 1) next to the executable
-    let mut candidates: Vec<PathBuf> = Vec::new();
+// Returns the merged config and every file it was read from (empty when running on auto-discovery only)
+fn load_config() -> Result<(Config, Vec<PathBuf>)> {
+    let paths = config_files();
+    // None found: empty config, relying on auto-discovery
+    let cfg = load_config_files(&paths)?;
+    Ok((cfg, paths))
+}
+
+// Every file must parse on its own (so errors name the file), then later files override earlier ones
+fn load_config_files(paths: &[PathBuf]) -> Result<Config> {
+    match paths {
+        [] => Ok(Config::default()),
+        [path] => parse_config_file(path),
+        _ => {
+            let mut merged = serde_json::Value::Object(Default::default());
+            for path in paths {
+                parse_config_file(path)?;
+                merge_config_value(&mut merged, read_config_value(path)?);
+            }
+            let cfg = serde_json::from_value(merged).context("Merging config files")?;
+            // a project in one file and a build in another can still collide
+            check_unique_keys(&cfg)?;
+            Ok(cfg)
+        }
+    }
+}
 
+// Candidates in increasing precedence: a shared config next to the executable, then the compile-time
+// project root (debug builds), then the working directory
+fn config_candidates() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+
+    // 1) next to the executable
     if let Ok(exe) = std::env::current_exe() {
         if let Some(dir) = exe.parent() {
-            candidates.push(dir.join("projects.json"));
+            dirs.push(dir.to_path_buf());
         }
     }
 
-    // 2) current working directory (useful for `cargo run`)
+    // 2) project root at compile time
+    #[cfg(debug_assertions)]
+    {
+        dirs.push(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+    }
+
+    // 3) current working directory (useful for `cargo run`)
     if let Ok(cwd) = std::env::current_dir() {
-        candidates.push(cwd.join("projects.json"));
+        dirs.push(cwd);
+    }
+    let mut unique: Vec<PathBuf> = Vec::new();
+    for d in dirs {
+        if !unique.contains(&d) { unique.push(d); }
     }
 
-    // 3) project root at compile time
-    #[cfg(debug_assertions)]
-    {
-        candidates.push(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("projects.json"));
+    // In each directory JSON wins, then TOML, then YAML
+    const NAMES: [&str; 4] = ["projects.json", "projects.toml", "projects.yaml", "projects.yml"];
+    unique.iter().flat_map(|d| NAMES.iter().map(move |n| d.join(n))).collect()
+}
+
+// The config files in use: the first existing candidate of each directory
+fn config_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = Vec::new();
+    for candidate in config_candidates().into_iter().filter(|p| p.exists()) {
+        if !files.iter().any(|f| f.parent() == candidate.parent()) { files.push(candidate); }
+    }
+    files
+}
+
+fn config_mtimes(paths: &[PathBuf]) -> Vec<Option<std::time::SystemTime>> {
+    paths.iter().map(|p| fs::metadata(p).and_then(|m| m.modified()).ok()).collect()
+}
+
+fn join_paths(paths: &[PathBuf]) -> String {
+    paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
+}
+
+// A config file as a JSON value, whatever its format
+fn read_config_value(path: &Path) -> Result<serde_json::Value> {
+    let text = fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))?;
+    let ext = path.extension().map(|e| e.to_string_lossy().to_ascii_lowercase()).unwrap_or_default();
+    Ok(match ext.as_str() {
+        "toml" => toml::from_str(&text)?,
+        "yaml" | "yml" => serde_yaml::from_str(&text)?,
+        _ => serde_json::from_str(&text)?,
+    })
+}
+
+// `local` wins: targets are matched by "key", local color rules are tried first, highlights add up,
+// nested tables (notifications) merge per field and everything else is replaced
+fn merge_config_value(base: &mut serde_json::Value, local: serde_json::Value) {
+    use serde_json::Value;
+    let (Value::Object(base), Value::Object(local)) = (base, local) else { return; };
+    for (name, value) in local {
+        match (name.as_str(), base.get_mut(&name), value) {
+            ("projects" | "builds" | "remotes", Some(Value::Array(entries)), Value::Array(overrides)) => {
+                for entry in overrides {
+                    let key = entry.get("key").cloned();
+                    match entries.iter_mut().find(|e| key.is_some() && e.get("key") == key.as_ref()) {
+                        Some(existing) => *existing = entry,
+                        None => entries.push(entry),
+                    }
+                }
+            }
+            ("colors", Some(Value::Array(rules)), Value::Array(mut first)) => {
+                first.append(rules);
+                *rules = first;
+            }
+            ("highlights", Some(Value::Array(entries)), Value::Array(more)) => entries.extend(more),
+            (_, Some(existing @ Value::Object(_)), value @ Value::Object(_)) => merge_config_value(existing, value),
+            (_, _, value) => { base.insert(name, value); }
+        }
     }
+}
 
-    if let Some(path) = candidates.into_iter().find(|p| p.exists()) {
-        let bytes = fs::read(&path).with_context(|| format!("Reading {}", path.display()))?;
-        let cfg: Config = serde_json::from_slice(&bytes).with_context(|| format!("Parsing {}", path.display()))?;
-        Ok(cfg)
-    } else {
-        // Not found: return empty config and rely on auto-discovery
-        Ok(Config { projects: Vec::new(), builds: Vec::new() })
+// Deserialize by extension; anything unrecognised is read as JSON
+fn parse_config_file(path: &Path) -> Result<Config> {
+    let text = fs::read_to_string(path).with_context(|| format!("Reading {}", path.display()))?;
+    let ext = path.extension().map(|e| e.to_string_lossy().to_ascii_lowercase()).unwrap_or_default();
+    let cfg = match ext.as_str() {
+        "toml" => toml::from_str(&text).with_context(|| format!("Parsing {} as TOML", path.display()))?,
+        "yaml" | "yml" => serde_yaml::from_str(&text).with_context(|| format!("Parsing {} as YAML", path.display()))?,
+        _ => serde_json::from_str(&text).with_context(|| format!("Parsing {} as JSON", path.display()))?,
+    };
+    check_timestamp_format(&cfg)?;
+    check_accent_colors(&cfg)?;
+    Theme::new(&cfg.theme, None)?;
+    if let Some(level) = &cfg.stop_following_on {
+        Verbosity::parse(level).ok_or_else(|| anyhow!("\"stop_following_on\": unknown verbosity {:?} (Fatal, Error, Warning, Display, Log or Verbose)", level))?;
     }
+    if let Some(bell) = cfg.bell.as_deref().filter(|_| cfg.bell().is_none()) {
+        return Err(anyhow!("\"bell\": unknown kind {:?} (audible or visual)", bell));
+    }
+    check_unique_keys(&cfg).with_context(|| format!("In {}", path.display()))?;
+    for c in &cfg.category_palette {
+        Color::from_str(c).map_err(|_| anyhow!("\"category_palette\": unknown color {:?}", c))?;
+    }
+    Ok(cfg)
+}
+
+fn check_accent_colors(cfg: &Config) -> Result<()> {
+    let projects = cfg.projects.iter().map(|p| (&p.key, &p.color));
+    let builds = cfg.builds.iter().map(|b| (&b.key, &b.color));
+    let remotes = cfg.remotes.iter().map(|r| (&r.key, &r.color));
+    for (key, color) in projects.chain(builds).chain(remotes) {
+        if let Some(c) = color {
+            Color::from_str(c).map_err(|_| anyhow!("{:?}: unknown color {:?}", key, c))?;
+        }
+    }
+    Ok(())
+}
+
+// Number keys, merged views and the saved state all pick targets by key, so two targets can't share one
+// (compared case-insensitively, as discovered editors are)
+fn check_unique_keys(cfg: &Config) -> Result<()> {
+    let projects = cfg.projects.iter().map(|p| (&p.key, "project"));
+    let builds = cfg.builds.iter().map(|b| (&b.key, "build"));
+    let remotes = cfg.remotes.iter().map(|r| (&r.key, "remote"));
+    let mut seen: Vec<(&String, Vec<&str>)> = Vec::new();
+    for (key, kind) in projects.chain(builds).chain(remotes) {
+        match seen.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case(key)) {
+            Some((_, kinds)) => kinds.push(kind),
+            None => seen.push((key, vec![kind])),
+        }
+    }
+    let duplicates: Vec<String> = seen.iter()
+        .filter(|(_, kinds)| kinds.len() > 1)
+        .map(|(key, kinds)| format!("{:?} ({})", key, kinds.join(", ")))
+        .collect();
+    if duplicates.is_empty() { return Ok(()); }
+    Err(anyhow!("Duplicate target key {}; every project, build and remote needs its own \"key\"", duplicates.join(", ")))
+}
+
+// chrono panics while formatting with a broken pattern, so reject it up front
+fn check_timestamp_format(cfg: &Config) -> Result<()> {
+    use chrono::format::{Item, StrftimeItems};
+    if let Some(pattern) = cfg.timestamp_pattern() {
+        if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+            return Err(anyhow!("Invalid \"timestamp_format\": {:?}", pattern));
+        }
+    }
+    Ok(())
+}
+
+fn state_path_for(config_path: Option<&Path>) -> PathBuf {
+    let dir = config_path
+        .and_then(|p| p.parent().map(Path::to_path_buf))
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default();
+    dir.join(".unreal-tui-state.json")
 }
 
 fn log_path_from_uproject(uproject: &Path) -> Result<PathBuf> {
     let dir = uproject.parent().ok_or_else(|| anyhow!("Invalid .uproject path"))?;
     let stem = uproject.file_stem().ok_or_else(|| anyhow!("Invalid .uproject filename"))?
         .to_string_lossy().to_string();
-    Ok(dir.join("Saved").join("Logs").join(format!("{}.log", stem)))
+    Ok(newest_log_in(&dir.join("Saved").join("Logs"), &stem))
 }
 
 fn log_path_from_exe(exe: &Path) -> Result<PathBuf> {
     let dir = exe.parent().ok_or_else(|| anyhow!("Invalid .exe path"))?;
     let stem = exe.file_stem().ok_or_else(|| anyhow!("Invalid .exe filename"))?
         .to_string_lossy().to_string();
-    // Next to the exe there is a folder with the same name
-    Ok(dir.join(&stem).join("Saved").join("Logs").join(format!("{}.log", stem)))
-}
-
-fn classify_line(s: &str) -> Color {
-    let l = s.to_ascii_lowercase();
-    if l.contains("error") { Color::Red }
-    else if l.contains("warning") { Color::Yellow }
-    else { Color::White }
-}
-
-// Try to parse a COOK progress line like:
-// "LogCook: Display: Cooked packages 816 Packages Remain 4532 Total 5348"
-// Returns (cooked, remain, total). Total may be 0 if not present.
-fn parse_cook_progress_line(s: &str) -> Option<(u64, u64, u64)> {
-    let l = s.to_ascii_lowercase();
-    fn find_number_after(hay: &str, key: &str) -> Option<u64> {
-        if let Some(i) = hay.find(key) {
-            let mut j = i + key.len();
-            // skip spaces
-            while let Some(ch) = hay.chars().nth(j) { if ch.is_whitespace() { j += 1; } else { break; } }
-            // collect digits
-            let digits: String = hay[j..].chars().take_while(|c| c.is_ascii_digit()).collect();
-            if digits.is_empty() { None } else { digits.parse::<u64>().ok() }
-        } else { None }
-    }
-    let cooked = find_number_after(&l, "cooked packages ");
-    let remain = find_number_after(&l, "packages remain ");
-    let total = find_number_after(&l, "total ");
-    if cooked.is_some() || remain.is_some() {
-        Some((cooked.unwrap_or(0), remain.unwrap_or(0), total.unwrap_or(0)))
-    } else { None }
-}
-
-fn parse_log_components(s: &str) -> (Option<String>, Option<String>, String) {
-    // Extract first [timestamp] if present, skip second [thread] if present, then category before ':'
-    let mut i = 0usize;
-    let bytes = s.as_bytes();
-    let mut ts: Option<String> = None;
-
-    // helper to skip spaces
-    let mut skip_spaces = |i: usize| -> usize {
-        let mut j = i;
-        while j < bytes.len() && bytes[j].is_ascii_whitespace() { j += 1; }
-        j
+
+    // Binary inside <Game>/Binaries/<Platform>/ (Linux/macOS packages, or pointing at the real
+    // binary rather than the launcher): the stem may carry a "-Linux-Shipping" style suffix,
+    // so the game name comes from the folder above Binaries.
+    let in_binaries = dir.parent()
+        .filter(|p| p.file_name().is_some_and(|n| n.eq_ignore_ascii_case("Binaries")))
+        .and_then(|p| p.parent());
+    let (game_dir, name) = match in_binaries {
+        Some(root) => {
+            let name = root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or(stem);
+            (root.to_path_buf(), name)
+        }
+        // Next to the exe (or .sh launcher) there is a folder with the same name
+        None => (dir.join(&stem), stem),
+    };
+    let local = newest_log_in(&game_dir.join("Saved").join("Logs"), &name);
+    if !local.exists() {
+        if let Some(user) = user_log_path(&name).filter(|p| p.exists()) {
+            return Ok(user);
+        }
+    }
+    Ok(local)
+}
+
+// Newest `<stem>*.log` in `dir` (renamed via -LOG= or rotated to -backup-...), else `<stem>.log`
+fn newest_log_in(dir: &Path, stem: &str) -> PathBuf {
+    let prefix = stem.to_ascii_lowercase();
+    let newest = fs::read_dir(dir).ok().and_then(|entries| {
+        entries
+            .flatten()
+            .filter(|e| {
+                let name = e.file_name().to_string_lossy().to_ascii_lowercase();
+                // "Game.log", "Game-backup-...log", "Game_2.log", but not "GameServer.log"
+                name.ends_with(".log")
+                    && name.strip_prefix(&prefix).is_some_and(|rest| rest.starts_with(['.', '-', '_']))
+            })
+            .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+            .max_by_key(|(modified, _)| *modified)
+            .map(|(_, path)| path)
+    });
+    newest.unwrap_or_else(|| dir.join(format!("{}.log", stem)))
+}
+
+// Case-insensitive subsequence match of `query` in `text`: the char positions used and a score that favours
+// runs and word starts (`ue5g` ranks "UE5 Game" over "Unreal Engine 5 Gym"); None when a query char is missing
+fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut positions = Vec::new();
+    let mut score = 0i64;
+    let mut from = 0usize;
+    for q in query.chars().flat_map(char::to_lowercase) {
+        let at = (from..chars.len()).find(|&i| chars[i].to_lowercase().eq(std::iter::once(q)))?;
+        let prev = at.checked_sub(1).map(|p| chars[p]);
+        score += 1;
+        if at > 0 && positions.last() == Some(&(at - 1)) { score += 5; }
+        if prev.is_none_or(|p| !p.is_alphanumeric() || (p.is_lowercase() && chars[at].is_uppercase())) { score += 3; }
+        score -= (at - from).min(5) as i64; // gaps cost a little
+        positions.push(at);
+        from = at + 1;
+    }
+    Some((score, positions))
+}
+
+// `text` as spans with the chars at `positions` underlined and bold
+fn fuzzy_spans(text: &str, positions: &[usize], style: Style) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (i, c) in text.chars().enumerate() {
+        let s = if positions.contains(&i) { style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED) } else { style };
+        match spans.last_mut() {
+            Some(last) if last.style == s => last.content.to_mut().push(c),
+            _ => spans.push(Span::styled(c.to_string(), s)),
+        }
+    }
+    spans
+}
+
+// Select list form of a configured path
+fn shorten_path(path: &Path, style: PathStyle) -> String {
+    match style {
+        PathStyle::Full => path.display().to_string(),
+        PathStyle::Home => {
+            let home = std::env::var_os(if cfg!(windows) { "USERPROFILE" } else { "HOME" });
+            match home.as_deref().and_then(|h| path.strip_prefix(h).ok()) {
+                Some(rest) => Path::new("~").join(rest).display().to_string(),
+                None => path.display().to_string(),
+            }
+        }
+        PathStyle::Folder => {
+            let file = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            match path.parent().and_then(|p| p.file_name()) {
+                Some(dir) => format!("…/{}/{}", dir.to_string_lossy(), file),
+                None => path.display().to_string(),
+            }
+        }
+    }
+}
+
+// Footer form of a log source: files home-relative and cut in the middle when long
+fn source_label(log: &LogSource) -> String {
+    const MAX: usize = 60;
+    match log {
+        LogSource::File { path, .. } => truncate_middle(&shorten_path(path, PathStyle::Home), MAX),
+        LogSource::Replay { path, .. } => format!("replay of {}", truncate_middle(&shorten_path(path, PathStyle::Home), MAX)),
+        other => other.to_string(),
+    }
+}
+
+// "~/Projects/MyG…/Saved/Logs/MyGame.log": the start and the end of `s` around an ellipsis
+fn truncate_middle(s: &str, max: usize) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    if chars.len() <= max || max < 3 { return s.to_string(); }
+    let tail = (max - 1) * 2 / 3; // the file name matters most
+    let head = max - 1 - tail;
+    format!("{}…{}", chars[..head].iter().collect::<String>(), chars[chars.len() - tail..].iter().collect::<String>())
+}
+
+// The other `*.log` files in the folder of `main`, e.g. crash logs next to the editor's; the newest few
+fn sibling_logs(main: &Path) -> Vec<PathBuf> {
+    const LIMIT: usize = 8;
+    let Some(dir) = main.parent() else { return Vec::new(); };
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new(); };
+    // rotated copies of earlier sessions ("Game-backup-2024.01.01-10.00.00.log") are history, not news
+    let mut logs: Vec<(std::time::SystemTime, PathBuf)> = entries.flatten()
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|e| e.eq_ignore_ascii_case("log")) && p != main)
+        .filter(|p| !p.file_name().is_some_and(|n| n.to_string_lossy().to_ascii_lowercase().contains("-backup-")))
+        .filter_map(|p| Some((p.metadata().ok()?.modified().ok()?, p)))
+        .collect();
+    // newest first, so a folder full of old logs can't open dozens of tails
+    logs.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    logs.truncate(LIMIT);
+    logs.into_iter().map(|(_, p)| p).collect()
+}
+
+// Newest `*.log` directly inside `dir`
+fn newest_any_log(dir: &Path) -> Option<(std::time::SystemTime, PathBuf)> {
+    fs::read_dir(dir).ok()?
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().to_ascii_lowercase().ends_with(".log"))
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .max_by_key(|(modified, _)| *modified)
+}
+
+// Explains a missing log file when its folder is missing or holds no logs under that name,
+// pointing at the newest log in a neighbouring `Saved/Logs` when there is one
+fn log_path_warning(path: &Path) -> Option<String> {
+    if path.exists() { return None; }
+    let dir = path.parent()?;
+    if let Some((_, newest)) = newest_any_log(dir) {
+        return Some(format!("{} not found; newest log in that folder is {}", path.display(), newest.display()));
+    }
+    // <Game>/Saved/Logs/x.log: try the folders next to <Game> and one level below them
+    // (packaged builds nest as Windows/<Game>), plus the folders next to its parent
+    let subdirs = |p: &Path| -> Vec<PathBuf> {
+        fs::read_dir(p).into_iter().flatten().flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect()
+    };
+    let parent = dir.parent().and_then(Path::parent).and_then(Path::parent);
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    if let Some(parent) = parent {
+        for sibling in subdirs(parent) {
+            candidates.extend(subdirs(&sibling));
+            candidates.push(sibling);
+        }
+        if let Some(grandparent) = parent.parent() {
+            candidates.extend(subdirs(grandparent));
+        }
+    }
+    let nearby = candidates.iter()
+        .filter_map(|d| newest_any_log(&d.join("Saved").join("Logs")))
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, p)| p);
+    let problem = if dir.is_dir() {
+        format!("No .log files in {}", dir.display())
+    } else {
+        format!("Log folder {} does not exist", dir.display())
     };
+    Some(match nearby {
+        Some(p) => format!("{}; newest log nearby is {} (set \"log_path\" to use it)", problem, p.display()),
+        None => format!("{}; check the path or set \"log_path\"", problem),
+    })
+}
+
+// Packaged games on Linux/macOS log into the user's profile instead of next to the binary
+#[cfg(target_os = "linux")]
+fn user_log_path(name: &str) -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join(".config").join("Epic").join(name).join("Saved").join("Logs").join(format!("{}.log", name)))
+}
+
+#[cfg(target_os = "macos")]
+fn user_log_path(name: &str) -> Option<PathBuf> {
+    let home = std::env::var_os("HOME")?;
+    Some(PathBuf::from(home).join("Library").join("Logs").join(name).join(format!("{}.log", name)))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn user_log_path(_name: &str) -> Option<PathBuf> {
+    None
+}
+
+// "D:/Proj/Source/Foo.cpp(123)" style references from compiler errors, asserts and ensures
+fn find_source_ref(text: &str) -> Option<(PathBuf, usize)> {
+    static RE: OnceLock<regex::Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        regex::Regex::new(r#"((?:[A-Za-z]:)?[^\s:()\[\]<>|"']+\.(?:cpp|cc|c|h|hpp|inl|cs|usf|ush|ini|py))\((\d+)(?:,\d+)?\)"#)
+            .expect("valid source reference regex")
+    });
+    let caps = re.captures(text)?;
+    Some((PathBuf::from(&caps[1]), caps[2].parse().ok()?))
+}
+
+// `$EDITOR +<line> <file>`, the form vim, nano, emacs and friends understand
+fn run_editor(path: &Path, line: usize) -> Result<()> {
+    let editor = std::env::var("EDITOR").context("$EDITOR is not set")?;
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().ok_or_else(|| anyhow!("$EDITOR is empty"))?;
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(format!("+{}", line))
+        .arg(path)
+        .status()
+        .with_context(|| format!("cannot start {}", program))?;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", program, status));
+    }
+    Ok(())
+}
+
+// NO_COLOR / TERM=dumb: https://no-color.org asks for a non-empty NO_COLOR
+fn colors_disabled() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+        || std::env::var("TERM").is_ok_and(|t| t == "dumb")
+}
+
+// Drop every color left after drawing; a cell with a background (gauges, selection, crash rows, the
+// minimap's viewport) turns to reverse video instead so it still stands out
+fn strip_colors(buf: &mut ratatui::buffer::Buffer) {
+    for cell in buf.content.iter_mut() {
+        if cell.bg != Color::Reset {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+        cell.underline_color = Color::Reset;
+    }
+}
+
+// A row's background only covers its text; pad rows that have one to the full width
+fn fill_row(line: Line<'_>, width: usize) -> Line<'_> {
+    let used = line.width();
+    if line.style.bg.is_none() || used >= width { return line; }
+    let mut line = line;
+    line.spans.push(Span::raw(" ".repeat(width - used)));
+    line
+}
 
+// Break styled spans into rows of `width` chars, preferring to break after a space;
+// rows after the first are indented by `indent` columns
+fn wrap_spans(spans: &[Span], width: usize, indent: usize) -> Vec<Line<'static>> {
+    let chars: Vec<(char, Style)> = spans.iter()
+        .flat_map(|s| s.content.chars().map(move |c| (c, s.style)))
+        .collect();
+    let mut rows: Vec<Line<'static>> = Vec::new();
     let mut pos = 0usize;
-    if bytes.get(0) == Some(&b'[') {
-        if let Some(end) = s.find(']') {
-            ts = Some(s[1..end].to_string());
-            pos = end + 1;
-            pos = skip_spaces(pos);
-            // optional second bracket [number]
-            if bytes.get(pos) == Some(&b'[') {
-                if let Some(end2rel) = s[pos..].find(']') {
-                    pos = pos + end2rel + 1;
-                    pos = skip_spaces(pos);
-                }
+    while pos < chars.len() || rows.is_empty() {
+        let pad = if rows.is_empty() { 0 } else { indent };
+        let cap = width.saturating_sub(pad).max(1);
+        let mut end = (pos + cap).min(chars.len());
+        if end < chars.len() {
+            if let Some(space) = chars[pos..end].iter().rposition(|(c, _)| *c == ' ').filter(|&i| i > 0) {
+                end = pos + space + 1;
+            }
+        }
+        // regroup runs of equal style into spans
+        let mut row: Vec<Span<'static>> = Vec::new();
+        if pad > 0 {
+            row.push(Span::raw(" ".repeat(pad)));
+        }
+        let mut text = String::new();
+        let mut style = chars.get(pos).map(|(_, st)| *st).unwrap_or_default();
+        for &(c, st) in &chars[pos..end] {
+            if st != style && !text.is_empty() {
+                row.push(Span::styled(std::mem::take(&mut text), style));
+            }
+            style = st;
+            text.push(c);
+        }
+        if !text.is_empty() {
+            row.push(Span::styled(text, style));
+        }
+        rows.push(Line::from(row));
+        pos = end;
+    }
+    rows
+}
+
+// Same message in the same category; what collapse mode folds into one row
+fn is_repeat(a: &LogLine, b: &LogLine) -> bool {
+    a.category == b.category && a.level == b.level && a.display_message() == b.display_message()
+}
+
+// Slice of a `total`-line buffer shown by minimap cell `row` of `rows`
+fn minimap_cell_range(row: usize, rows: usize, total: usize) -> std::ops::Range<usize> {
+    (row * total / rows)..((row + 1) * total / rows)
+}
+
+// Multi-line grouping: attach `line` to the previous line of its source when it has no
+// timestamp/category of its own and that line heads (or already belongs to) a group
+fn group_line(lines: &[LogLine], line: &mut LogLine, color_rules: &[ColorRule]) {
+    if !line.is_continuation_candidate() { return; }
+    let Some(parent) = lines.iter().rev().find(|p| p.source == line.source) else { return; };
+    if parent.continuation || !parent.is_continuation_candidate() {
+        line.continue_from(parent, color_rules);
+    }
+}
+
+// Re-run grouping over a whole buffer after Z
+fn regroup_lines(lines: &mut [LogLine], group: bool, color_rules: &[ColorRule]) {
+    for i in 0..lines.len() {
+        let (before, rest) = lines.split_at_mut(i);
+        rest[0].detach(color_rules);
+        if group {
+            group_line(before, &mut rest[0], color_rules);
+        }
+    }
+}
+
+// One row per category after a TOTAL row, which alone carries the session duration
+fn stats_csv(stats: &Stats) -> String {
+    let levels = Verbosity::ALL.map(|v| v.as_str().to_ascii_lowercase()).join(",");
+    let mut out = format!("category,lines,errors,warnings,{},duration_secs\n", levels);
+    let counts = |levels: &LevelCounts| levels.0.map(|n| n.to_string()).join(",");
+    let duration = stats.duration_secs.map(|d| d.to_string()).unwrap_or_default();
+    out += &format!("TOTAL,{},{},{},{},{}\n", stats.lines, stats.errors, stats.warnings, counts(&stats.levels), duration);
+    for r in &stats.rows {
+        out += &format!("{},{},{},{},{},\n", r.category, r.lines, r.errors, r.warnings, counts(&r.levels));
+    }
+    out
+}
+
+// Drop the oldest lines beyond `cap`; returns how many were removed
+fn cap_scrollback(lines: &mut Vec<LogLine>, cap: usize) -> usize {
+    if lines.len() <= cap { return 0; }
+    let overflow = lines.len() - cap;
+    lines.drain(0..overflow);
+    overflow
+}
+
+// Category palette: readable on dark backgrounds and distinct from the red/yellow used for errors and warnings
+const DEFAULT_CATEGORY_PALETTE: [Color; 10] = [
+    Color::Cyan, Color::LightBlue, Color::LightGreen, Color::LightMagenta, Color::LightCyan,
+    Color::Rgb(255, 175, 95), Color::Rgb(135, 175, 255), Color::Rgb(175, 215, 135), Color::Rgb(215, 175, 255), Color::Rgb(95, 215, 175),
+];
+
+// Stable per-name color: the same category always lands on the same palette entry (FNV-1a)
+fn color_for_category(cat: &str, palette: &[Color]) -> Color {
+    if palette.is_empty() { return Color::Cyan; }
+    let h = cat.bytes().fold(0xcbf2_9ce4_8422_2325u64, |acc, b| (acc ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+    palette[(h % palette.len() as u64) as usize]
+}
+
+// Stable per-source tag color so merged logs stay distinguishable
+fn source_color(source: &str) -> Color {
+    const PALETTE: [Color; 6] = [Color::LightGreen, Color::LightMagenta, Color::LightBlue, Color::LightCyan, Color::LightYellow, Color::LightRed];
+    let h = source.bytes().fold(0usize, |acc, b| acc.wrapping_mul(31).wrapping_add(b as usize));
+    PALETTE[h % PALETTE.len()]
+}
+
+// "~1h 05m", "~4m 30s" or "~45s"
+fn format_eta(d: Duration) -> String {
+    let s = d.as_secs();
+    if s >= 3600 { format!("~{}h {:02}m", s / 3600, (s / 60) % 60) }
+    else if s >= 60 { format!("~{}m {:02}s", s / 60, s % 60) }
+    else { format!("~{}s", s) }
+}
+
+// "+mm:ss.mmm" (minutes keep counting past the hour), "-" prefix for lines before the start
+fn format_elapsed(d: chrono::TimeDelta) -> String {
+    let sign = if d < chrono::TimeDelta::zero() { '-' } else { '+' };
+    let ms = d.num_milliseconds().unsigned_abs();
+    format!("{}{:02}:{:02}.{:03}", sign, ms / 60_000, (ms / 1000) % 60, ms % 1000)
+}
+
+fn format_bytes(n: usize) -> String {
+    const KB: f64 = 1024.0;
+    let n = n as f64;
+    if n < KB { format!("{} B", n) }
+    else if n < KB * KB { format!("{:.1} KB", n / KB) }
+    else if n < KB * KB * KB { format!("{:.1} MB", n / (KB * KB)) }
+    else { format!("{:.1} GB", n / (KB * KB * KB)) }
+}
+
+// Least free space among the volumes holding `paths` (the disk with the longest matching mount point)
+fn free_disk_space(paths: &[&PathBuf]) -> Option<u64> {
+    use sysinfo::{DiskExt as _, System, SystemExt as _};
+    let mut sys = System::new();
+    sys.refresh_disks_list();
+    paths.iter()
+        .filter_map(|p| {
+            // the log itself may not exist yet; its folder usually does
+            let p = p.ancestors().find_map(|a| fs::canonicalize(a).ok())?;
+            sys.disks().iter()
+                .filter(|d| p.starts_with(d.mount_point()))
+                .max_by_key(|d| d.mount_point().as_os_str().len())
+                .map(|d| d.available_space())
+        })
+        .min()
+}
+
+// The library's line colors on screen, and colors parsed from the config for color rules
+fn tui_color(c: LineColor) -> Color {
+    match c {
+        LineColor::Reset => Color::Reset,
+        LineColor::Black => Color::Black,
+        LineColor::Red => Color::Red,
+        LineColor::Green => Color::Green,
+        LineColor::Yellow => Color::Yellow,
+        LineColor::Blue => Color::Blue,
+        LineColor::Magenta => Color::Magenta,
+        LineColor::Cyan => Color::Cyan,
+        LineColor::Gray => Color::Gray,
+        LineColor::DarkGray => Color::DarkGray,
+        LineColor::LightRed => Color::LightRed,
+        LineColor::LightGreen => Color::LightGreen,
+        LineColor::LightYellow => Color::LightYellow,
+        LineColor::LightBlue => Color::LightBlue,
+        LineColor::LightMagenta => Color::LightMagenta,
+        LineColor::LightCyan => Color::LightCyan,
+        LineColor::White => Color::White,
+        LineColor::Indexed(i) => Color::Indexed(i),
+        LineColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+fn line_color(c: Color) -> LineColor {
+    match c {
+        Color::Reset => LineColor::Reset,
+        Color::Black => LineColor::Black,
+        Color::Red => LineColor::Red,
+        Color::Green => LineColor::Green,
+        Color::Yellow => LineColor::Yellow,
+        Color::Blue => LineColor::Blue,
+        Color::Magenta => LineColor::Magenta,
+        Color::Cyan => LineColor::Cyan,
+        Color::Gray => LineColor::Gray,
+        Color::DarkGray => LineColor::DarkGray,
+        Color::LightRed => LineColor::LightRed,
+        Color::LightGreen => LineColor::LightGreen,
+        Color::LightYellow => LineColor::LightYellow,
+        Color::LightBlue => LineColor::LightBlue,
+        Color::LightMagenta => LineColor::LightMagenta,
+        Color::LightCyan => LineColor::LightCyan,
+        Color::White => LineColor::White,
+        Color::Indexed(i) => LineColor::Indexed(i),
+        Color::Rgb(r, g, b) => LineColor::Rgb(r, g, b),
+    }
+}
+
+// SGR escape selecting `color` as foreground, for colored exports
+fn ansi_fg(color: LineColor) -> String {
+    match color {
+        LineColor::Black => "\x1b[30m".to_string(),
+        LineColor::Red => "\x1b[31m".to_string(),
+        LineColor::Green => "\x1b[32m".to_string(),
+        LineColor::Yellow => "\x1b[33m".to_string(),
+        LineColor::Blue => "\x1b[34m".to_string(),
+        LineColor::Magenta => "\x1b[35m".to_string(),
+        LineColor::Cyan => "\x1b[36m".to_string(),
+        LineColor::Gray => "\x1b[37m".to_string(),
+        LineColor::DarkGray => "\x1b[90m".to_string(),
+        LineColor::LightRed => "\x1b[91m".to_string(),
+        LineColor::LightGreen => "\x1b[92m".to_string(),
+        LineColor::LightYellow => "\x1b[93m".to_string(),
+        LineColor::LightBlue => "\x1b[94m".to_string(),
+        LineColor::LightMagenta => "\x1b[95m".to_string(),
+        LineColor::LightCyan => "\x1b[96m".to_string(),
+        LineColor::White => "\x1b[97m".to_string(),
+        LineColor::Indexed(i) => format!("\x1b[38;5;{}m", i),
+        LineColor::Rgb(r, g, b) => format!("\x1b[38;2;{};{};{}m", r, g, b),
+        LineColor::Reset => "\x1b[39m".to_string(),
+    }
+}
+
+// Split spans so every match of `re` gets `style` patched on top
+fn highlight_regex<'a>(spans: Vec<Span<'a>>, re: &regex::Regex, style: Style) -> Vec<Span<'a>> {
+    let mut out: Vec<Span<'a>> = Vec::with_capacity(spans.len());
+    for span in spans {
+        if !re.is_match(&span.content) {
+            out.push(span);
+            continue;
+        }
+        let content: Cow<'a, str> = span.content;
+        let mut last = 0usize;
+        for m in re.find_iter(&content).filter(|m| !m.is_empty()) {
+            if m.start() > last {
+                out.push(Span::styled(content[last..m.start()].to_string(), span.style));
+            }
+            out.push(Span::styled(m.as_str().to_string(), span.style.patch(style)));
+            last = m.end();
+        }
+        if last < content.len() {
+            out.push(Span::styled(content[last..].to_string(), span.style));
+        }
+    }
+    out
+}
+
+// Styles by role rather than by color, so a theme can swap them. Lines keep the color the library gave them
+// (see classify_line); the built-in verbosity colors among those are recolored here at render time
+#[derive(Debug, Clone)]
+struct Theme {
+    name: &'static str,
+    fatal: Style,
+    error: Style,
+    warning: Style,
+    info: Style,
+    verbose: Style,
+    category: Option<Style>,   // None: each category gets a palette color by name
+    timestamp: Style,
+    header_project: Color,     // title/header accents when a target sets no `color`
+    header_build: Color,
+    header_remote: Color,
+    accent: Color,             // positive UI state; chrome otherwise borrows the error/warning colors
+    highlight: Style,
+    error_band: Color,         // dark enough that the message text stays readable on top
+    warning_band: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            name: "default",
+            fatal: Style::default().fg(Color::LightRed),
+            error: Style::default().fg(Color::Red),
+            warning: Style::default().fg(Color::Yellow),
+            info: Style::default().fg(Color::White),
+            verbose: Style::default().fg(Color::Gray),
+            category: None,
+            timestamp: Style::default().fg(Color::DarkGray),
+            header_project: Color::Cyan,
+            header_build: Color::Magenta,
+            header_remote: Color::Blue,
+            accent: Color::Green,
+            highlight: Style::default().bg(Color::Yellow).fg(Color::Black),
+            error_band: Color::Rgb(72, 16, 16),
+            warning_band: Color::Rgb(64, 52, 8),
+        }
+    }
+}
+
+impl Theme {
+    const NAMES: [&'static str; 3] = ["default", "colorblind", "monochrome"];
+
+    fn builtin(name: &str) -> Option<Theme> {
+        match name {
+            "default" => Some(Theme::default()),
+            // orange for errors, blue for warnings (Okabe-Ito), told apart without red/green vision
+            "colorblind" => Some(Theme {
+                name: "colorblind",
+                fatal: Style::default().fg(Color::Rgb(213, 94, 0)).add_modifier(Modifier::BOLD),
+                error: Style::default().fg(Color::Rgb(230, 159, 0)),
+                warning: Style::default().fg(Color::Rgb(86, 180, 233)),
+                header_project: Color::Rgb(86, 180, 233),
+                header_build: Color::Rgb(204, 121, 167),
+                header_remote: Color::Rgb(0, 114, 178),
+                accent: Color::Rgb(0, 158, 115),
+                highlight: Style::default().bg(Color::Rgb(240, 228, 66)).fg(Color::Black),
+                error_band: Color::Rgb(80, 44, 0),
+                warning_band: Color::Rgb(0, 44, 72),
+                ..Theme::default()
+            }),
+            // no colors at all: severity by reverse video, bold and dim (NO_COLOR)
+            "monochrome" => Some(Theme {
+                name: "monochrome",
+                fatal: Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD),
+                error: Style::default().add_modifier(Modifier::REVERSED),
+                warning: Style::default().add_modifier(Modifier::BOLD),
+                info: Style::default(),
+                verbose: Style::default().add_modifier(Modifier::DIM),
+                category: Some(Style::default().add_modifier(Modifier::ITALIC)),
+                timestamp: Style::default().add_modifier(Modifier::DIM),
+                header_project: Color::Reset,
+                header_build: Color::Reset,
+                header_remote: Color::Reset,
+                accent: Color::Reset,
+                highlight: Style::default().add_modifier(Modifier::REVERSED | Modifier::UNDERLINED),
+                error_band: Color::Reset,
+                warning_band: Color::Reset,
+            }),
+            _ => None,
+        }
+    }
+
+    // `base` overrides the config's base (Ctrl+T); the config's per-role values apply on top either way
+    fn new(cfg: &ThemeConfig, base: Option<&str>) -> Result<Theme> {
+        let name = base.or(cfg.base.as_deref()).unwrap_or("default");
+        let mut theme = Theme::builtin(name)
+            .ok_or_else(|| anyhow!("\"theme\": unknown base {:?} ({})", name, Theme::NAMES.join(" or ")))?;
+        let style = |role: &str, spec: &Option<String>, into: &mut Style| -> Result<()> {
+            if let Some(spec) = spec {
+                *into = parse_style(spec).with_context(|| format!("\"theme\": bad {:?} style {:?}", role, spec))?;
+            }
+            Ok(())
+        };
+        style("fatal", &cfg.fatal, &mut theme.fatal)?;
+        style("error", &cfg.error, &mut theme.error)?;
+        style("warning", &cfg.warning, &mut theme.warning)?;
+        style("info", &cfg.info, &mut theme.info)?;
+        style("verbose", &cfg.verbose, &mut theme.verbose)?;
+        style("timestamp", &cfg.timestamp, &mut theme.timestamp)?;
+        style("highlight", &cfg.highlight, &mut theme.highlight)?;
+        if let Some(spec) = &cfg.category {
+            theme.category = Some(parse_style(spec).with_context(|| format!("\"theme\": bad \"category\" style {:?}", spec))?);
+        }
+        let accent = |role: &str, spec: &Option<String>, into: &mut Color| -> Result<()> {
+            if let Some(spec) = spec {
+                *into = Color::from_str(spec).map_err(|_| anyhow!("\"theme\": {:?}: unknown color {:?}", role, spec))?;
             }
+            Ok(())
+        };
+        accent("header_project", &cfg.header_project, &mut theme.header_project)?;
+        accent("header_build", &cfg.header_build, &mut theme.header_build)?;
+        accent("header_remote", &cfg.header_remote, &mut theme.header_remote)?;
+        accent("accent", &cfg.accent, &mut theme.accent)?;
+        Ok(theme)
+    }
+
+    // Error/warning colors for UI chrome (gauges, borders, status text); none when the role is colorless
+    fn error_color(&self) -> Color {
+        self.error.fg.unwrap_or(Color::Reset)
+    }
+
+    fn warning_color(&self) -> Color {
+        self.warning.fg.unwrap_or(Color::Reset)
+    }
+
+    // Crash banner and row: white on the error color, or the error style itself in monochrome
+    fn alert(&self) -> Style {
+        match self.error.fg {
+            Some(c) => Style::default().bg(c).fg(Color::White),
+            None => self.error,
+        }.add_modifier(Modifier::BOLD)
+    }
+
+    // Text style of a line colored `color`: the verbosity colors classify_line hands out map to their roles,
+    // anything else (color rules, ANSI) is kept
+    fn line_style(&self, color: LineColor) -> Style {
+        match color {
+            LineColor::LightRed => self.fatal,
+            LineColor::Red => self.error,
+            LineColor::Yellow => self.warning,
+            LineColor::White => self.info,
+            LineColor::Gray => self.verbose,
+            c => Style::default().fg(tui_color(c)),
         }
     }
+}
+
+// "bold underlined #E69F00 on black": modifiers, then a foreground color, then an optional background
+fn parse_style(spec: &str) -> Result<Style> {
+    let mut style = Style::default();
+    let mut words = spec.split_whitespace();
+    while let Some(word) = words.next() {
+        style = match word.to_ascii_lowercase().as_str() {
+            "bold" => style.add_modifier(Modifier::BOLD),
+            "dim" => style.add_modifier(Modifier::DIM),
+            "italic" => style.add_modifier(Modifier::ITALIC),
+            "underlined" => style.add_modifier(Modifier::UNDERLINED),
+            "reversed" => style.add_modifier(Modifier::REVERSED),
+            "on" => {
+                let bg = words.next().ok_or_else(|| anyhow!("\"on\" needs a color"))?;
+                style.bg(Color::from_str(bg).map_err(|_| anyhow!("unknown color {:?}", bg))?)
+            }
+            _ => style.fg(Color::from_str(word).map_err(|_| anyhow!("unknown color or modifier {:?}", word))?),
+        };
+    }
+    Ok(style)
+}
+
+fn accent_color(spec: Option<&str>, default: Color) -> Color {
+    spec.and_then(|c| Color::from_str(c).ok()).unwrap_or(default)
+}
 
-    let rest = s[pos..].trim_start();
-    // Extract category if like Word: (no spaces before colon)
-    let mut category: Option<String> = None;
-    let mut message = rest.to_string();
-    if let Some(colon_idx) = rest.find(':') {
-        let (left, right) = rest.split_at(colon_idx);
-        if !left.is_empty() && !left.contains(' ') {
-            category = Some(left.to_string());
-            message = right.trim_start_matches(':').trim_start().to_string();
+fn compile_highlights(entries: &[HighlightConfig]) -> Result<Vec<Highlight>> {
+    entries.iter().enumerate().map(|(i, h)| {
+        let color = Color::from_str(&h.color)
+            .map_err(|_| anyhow!("highlight #{}: unknown color {:?}", i + 1, h.color))?;
+        let regex = regex::Regex::new(&h.pattern)
+            .with_context(|| format!("highlight #{}: bad pattern {:?}", i + 1, h.pattern))?;
+        Ok(Highlight { regex, color })
+    }).collect()
+}
+
+fn compile_filter_presets(entries: &[FilterPresetConfig]) -> Result<Vec<FilterPreset>> {
+    let mut presets: Vec<FilterPreset> = Vec::new();
+    for (i, p) in entries.iter().enumerate() {
+        let key = p.key.strip_prefix(['F', 'f']).and_then(|n| n.parse::<u8>().ok()).filter(|n| (1..=12).contains(n))
+            .ok_or_else(|| anyhow!("preset {:?}: key must be F1 to F12, not {:?}", p.name, p.key))?;
+        if let Some(other) = presets.iter().find(|o| o.key == key) {
+            return Err(anyhow!("presets {:?} and {:?} are both bound to F{}", other.name, p.name, key));
         }
+        let min_verbosity = match &p.min_verbosity {
+            Some(level) => Some(Verbosity::parse(level).ok_or_else(|| {
+                anyhow!("preset {:?}: unknown verbosity {:?} (Fatal, Error, Warning, Display, Log or Verbose)", p.name, level)
+            })?),
+            None => None,
+        };
+        let regex = p.regex.as_deref()
+            .map(|re| regex::Regex::new(re).with_context(|| format!("preset {:?}: bad regex {:?}", p.name, re)))
+            .transpose()?;
+        if p.categories.is_empty() && min_verbosity.is_none() && regex.is_none() {
+            return Err(anyhow!("preset #{} ({:?}) filters nothing; give it categories, min_verbosity or regex", i + 1, p.name));
+        }
+        presets.push(FilterPreset { name: p.name.clone(), key, categories: p.categories.iter().cloned().collect(), min_verbosity, regex });
     }
-    (ts, category, message)
+    Ok(presets)
+}
+
+fn compile_progress_parsers(entries: &[ProgressParserConfig]) -> Result<Vec<ProgressParser>> {
+    entries.iter().map(|p| {
+        let regex = regex::Regex::new(&p.regex)
+            .with_context(|| format!("progress {:?}: bad regex {:?}", p.label, p.regex))?;
+        let names: Vec<&str> = regex.capture_names().flatten().collect();
+        if !names.contains(&"current") || !names.contains(&"total") {
+            return Err(anyhow!("progress {:?}: the regex needs (?P<current>...) and (?P<total>...) groups", p.label));
+        }
+        let color = match &p.color {
+            Some(c) => Color::from_str(c).map_err(|_| anyhow!("progress {:?}: unknown color {:?}", p.label, c))?,
+            None => Color::Cyan,
+        };
+        Ok(ProgressParser { label: p.label.clone(), regex, color })
+    }).collect()
 }
 
-trait ListStateExt {
-    fn with_selected(self, idx: Option<usize>) -> Self;
+fn compile_color_rules(rules: &[ColorRuleConfig]) -> Result<Vec<ColorRule>> {
+    rules.iter().enumerate().map(|(i, r)| {
+        let color = Color::from_str(&r.color)
+            .map_err(|_| anyhow!("rule #{}: unknown color {:?}", i + 1, r.color))?;
+        let matcher = match (&r.contains, &r.regex) {
+            (Some(sub), None) => Matcher::Contains(sub.to_lowercase()),
+            (None, Some(re)) => Matcher::Regex(
+                regex::Regex::new(re).with_context(|| format!("rule #{}: bad regex {:?}", i + 1, re))?,
+            ),
+            _ => return Err(anyhow!("rule #{}: set exactly one of \"contains\" or \"regex\"", i + 1)),
+        };
+        Ok(ColorRule { matcher, color: line_color(color) })
+    }).collect()
 }
-impl ListStateExt for ratatui::widgets::ListState {
-    fn with_selected(mut self, idx: Option<usize>) -> Self { self.select(idx); self }
+
+// Parsed verbosity, or a guess from "error"/"warning" substrings for lines without one
+fn line_severity(l: &LogLine) -> Option<Verbosity> {
+    // a grouped stack trace counts once, through its header line
+    if l.continuation { return None; }
+    if l.level.is_some() { return l.level; }
+    let lower = l.text.to_ascii_lowercase();
+    if lower.contains("error") { Some(Verbosity::Error) }
+    else if lower.contains("warning") { Some(Verbosity::Warning) }
+    else { None }
 }
 
 trait ProjectExt {
@@ -941,3 +5218,196 @@ impl BuildExt for Build {
         if self.name.trim().is_empty() { self.key.clone() } else { self.name.clone() }
     }
 }
+
+trait RemoteExt {
+    fn name_or_key(&self) -> String;
+}
+impl RemoteExt for Remote {
+    fn name_or_key(&self) -> String {
+        if self.name.trim().is_empty() { self.key.clone() } else { self.name.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(json: &str) -> Config {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn rejects_duplicate_target_keys() {
+        let cfg = config(r#"{
+            "projects": [{ "key": "game", "uproject": "Game/Game.uproject" }, { "key": "tools", "uproject": "Tools/Tools.uproject" }],
+            "builds": [{ "key": "Game", "exe": "Game.exe" }, { "key": "server", "exe": "Server.exe" }]
+        }"#);
+        let err = check_unique_keys(&cfg).unwrap_err().to_string();
+        assert_eq!(err, r#"Duplicate target key "game" (project, build); every project, build and remote needs its own "key""#);
+
+        let cfg = config(r#"{
+            "projects": [{ "key": "game", "uproject": "Game/Game.uproject" }],
+            "builds": [{ "key": "game-dev", "exe": "Game.exe" }]
+        }"#);
+        assert!(check_unique_keys(&cfg).is_ok());
+    }
+
+    #[test]
+    fn rejects_duplicate_keys_across_merged_files() {
+        let dir = std::env::temp_dir().join(format!("ue-tui-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("local")).unwrap();
+        let shared = dir.join("projects.json");
+        let local = dir.join("local").join("projects.json");
+        fs::write(&shared, r#"{ "projects": [{ "key": "game", "uproject": "Game/Game.uproject" }] }"#).unwrap();
+        fs::write(&local, r#"{ "builds": [{ "key": "game", "exe": "Game.exe" }] }"#).unwrap();
+        let result = load_config_files(&[shared, local]);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(result.unwrap_err().to_string().contains(r#""game" (project, build)"#));
+    }
+
+    #[test]
+    fn compiles_filter_presets() {
+        let cfg = config(r#"{ "filter_presets": [
+            { "name": "Errors", "key": "F1", "min_verbosity": "Error" },
+            { "name": "Render", "key": "f12", "categories": ["LogRenderer"], "regex": "RHI" }
+        ] }"#);
+        let presets = compile_filter_presets(&cfg.filter_presets).unwrap();
+        assert_eq!((presets[0].key, presets[0].min_verbosity), (1, Some(Verbosity::Error)));
+        assert_eq!(presets[1].key, 12);
+        assert!(presets[1].categories.contains("LogRenderer"));
+
+        let bad = |json: &str| compile_filter_presets(&config(json).filter_presets).unwrap_err().to_string();
+        assert!(bad(r#"{ "filter_presets": [{ "name": "A", "key": "F13", "min_verbosity": "Error" }] }"#).contains("F1 to F12"));
+        assert!(bad(r#"{ "filter_presets": [{ "name": "A", "key": "F2" }] }"#).contains("filters nothing"));
+        assert!(bad(r#"{ "filter_presets": [
+            { "name": "A", "key": "F2", "regex": "x" }, { "name": "B", "key": "F2", "regex": "y" }
+        ] }"#).contains("both bound to F2"));
+    }
+
+    #[test]
+    fn counts_motions_and_target_digits() {
+        let dir = std::env::temp_dir().join(format!("ue-tui-motion-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.log"), "").unwrap();
+        fs::write(dir.join("b.log"), "").unwrap();
+        let cfg = config(&format!(r#"{{ "projects": [
+            {{ "key": "a", "uproject": "A/A.uproject", "log_path": {:?} }},
+            {{ "key": "b", "uproject": "B/B.uproject", "log_path": {:?} }}
+        ] }}"#, dir.join("a.log"), dir.join("b.log")));
+        let mut app = App::new(cfg, Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        let keys = |app: &mut App, keys: &[KeyCode]| for &key in keys {
+            app.on_key(KeyEventKind::Press, key, false).unwrap();
+        };
+        let fill = |app: &mut App| for i in 0..200 {
+            let recorded: RecordedLine = serde_json::from_value(serde_json::json!({
+                "text": format!("LogTemp: {}", i), "ts": null, "category": "LogTemp", "level": null,
+                "message": i.to_string(), "source": null, "crash": false,
+            })).unwrap();
+            app.push_line(recorded.into_line(&[]));
+        };
+        app.open_numbered_target('1').unwrap();
+        app.last_body_area = Rect::new(0, 0, 80, 22);
+        fill(&mut app);
+
+        // 5↑ and 3↓ scroll by their count
+        keys(&mut app, &[KeyCode::Char('5'), KeyCode::Up]);
+        assert_eq!(app.scroll_from_bottom, 5);
+        keys(&mut app, &[KeyCode::Char('3'), KeyCode::Down]);
+        assert_eq!(app.scroll_from_bottom, 2);
+        // gg goes to the top, 100G to line 100
+        keys(&mut app, &[KeyCode::Char('g'), KeyCode::Char('g')]);
+        assert_eq!(app.selected_line, Some(0));
+        keys(&mut app, &[KeyCode::Char('1'), KeyCode::Char('0'), KeyCode::Char('0'), KeyCode::Char('G')]);
+        assert_eq!(app.selected_line, Some(99));
+        assert!(app.pending_motion.is_none());
+        // j takes no count: the digit is a target switch (there's no fifth one) and j still opens its prompt
+        keys(&mut app, &[KeyCode::Char('5'), KeyCode::Char('j')]);
+        assert_eq!(app.last_error.as_deref(), Some("No target 5 (2 configured)"));
+        assert!(app.input.as_ref().is_some_and(|input| input.prompt == Prompt::JumpTime));
+        app.input = None;
+
+        // a lone digit switches once the pause runs out
+        keys(&mut app, &[KeyCode::Char('2')]);
+        app.expire_pending_motion().unwrap();
+        assert_eq!(app.current_key.as_deref(), Some("a"));
+        app.pending_motion.as_mut().unwrap().since -= PENDING_MOTION_TIMEOUT;
+        app.expire_pending_motion().unwrap();
+        assert_eq!(app.current_key.as_deref(), Some("b"));
+        // ... or at once when another key follows, which then acts on the new target; Esc cancels
+        keys(&mut app, &[KeyCode::Char('1'), KeyCode::Esc]);
+        assert_eq!(app.current_key.as_deref(), Some("b"));
+        keys(&mut app, &[KeyCode::Char('1'), KeyCode::Char('t')]);
+        assert_eq!(app.current_key.as_deref(), Some("a"));
+        assert!(app.show_timestamp);
+
+        app.leave_view();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn theme_overrides_apply_on_top_of_the_base() {
+        let cfg = config(r#"{ "theme": { "base": "colorblind", "error": "bold magenta on black" } }"#);
+        let theme = Theme::new(&cfg.theme, None).unwrap();
+        assert_eq!(theme.line_style(LineColor::Red), Style::default().fg(Color::Magenta).bg(Color::Black).add_modifier(Modifier::BOLD));
+        assert_eq!(theme.line_style(LineColor::Yellow), Theme::builtin("colorblind").unwrap().warning);
+        assert_eq!(theme.line_style(LineColor::Green), Style::default().fg(Color::Green));
+        assert_eq!(Theme::new(&cfg.theme, Some("default")).unwrap().warning, Style::default().fg(Color::Yellow));
+
+        let cfg = config(r#"{ "theme": { "warning": "blinking yellow" } }"#);
+        assert!(format!("{:#}", Theme::new(&cfg.theme, None).unwrap_err()).contains("unknown color or modifier \"blinking\""));
+    }
+
+    #[test]
+    fn progress_parsers_need_current_and_total() {
+        let cfg = config(r#"{ "progress": [
+            { "label": "DDC", "regex": "Filling DDC: (?P<current>\\d+) of (?P<total>\\d+)" },
+            { "label": "Validate", "regex": "Validated (?P<current>\\d+) assets" }
+        ] }"#);
+        let err = compile_progress_parsers(&cfg.progress).unwrap_err().to_string();
+        assert!(err.contains("\"Validate\""), "{}", err);
+        let parsers = compile_progress_parsers(&cfg.progress[..1]).unwrap();
+        let mut app = App::new(Config::default(), Vec::new(), Vec::new(), Vec::new(), parsers, Vec::new());
+        app.update_progress("LogDerivedDataCache: Display: Filling DDC: 12 of 40");
+        let progress = app.progress.as_ref().unwrap();
+        assert_eq!((progress.parser, progress.current, progress.total), (0, 12, 40));
+        // other lines leave the gauge as it was
+        app.update_progress("LogDerivedDataCache: Display: Filling DDC: done");
+        assert_eq!(app.progress.as_ref().map(|p| p.current), Some(12));
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_runs_and_word_starts() {
+        assert_eq!(fuzzy_match("lyr", "Lyra Starter Game").map(|(_, p)| p), Some(vec![0, 1, 2]));
+        assert_eq!(fuzzy_match("SG", "Lyra Starter Game").map(|(_, p)| p), Some(vec![5, 13]));
+        assert!(fuzzy_match("xyz", "Lyra Starter Game").is_none());
+        let score = |t: &str| fuzzy_match("ue5g", t).unwrap().0;
+        assert!(score("UE5 Game") > score("Unreal Engine 5 Gym"));
+    }
+
+    #[test]
+    fn sibling_logs_skip_backups_and_keep_the_newest() {
+        let dir = std::env::temp_dir().join(format!("ue-tui-siblings-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let main = dir.join("Game.log");
+        for name in ["Game.log", "Game-backup-2024.01.01-10.00.00.log", "CrashReportClient.log", "notes.txt"] {
+            fs::write(dir.join(name), "x").unwrap();
+        }
+        let found = sibling_logs(&main);
+        for i in 0..10 {
+            fs::write(dir.join(format!("Extra{}.log", i)), "x").unwrap();
+        }
+        let capped = sibling_logs(&main);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(found, vec![dir.join("CrashReportClient.log")]);
+        assert_eq!(capped.len(), 8);
+        assert!(!capped.contains(&main));
+    }
+
+    #[test]
+    fn truncates_long_paths_in_the_middle() {
+        assert_eq!(truncate_middle("/tmp/Game.log", 60), "/tmp/Game.log");
+        let short = truncate_middle("/home/me/Projects/MyGame/Saved/Logs/MyGame.log", 30);
+        assert_eq!(short, "/home/me/P…ved/Logs/MyGame.log");
+        assert_eq!(short.chars().count(), 30);
+    }
+}